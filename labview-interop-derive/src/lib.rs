@@ -0,0 +1,97 @@
+//! Procedural macros for the labview-interop crate.
+//!
+//! These are re-exported from `labview-interop` with the `derive` feature
+//! so you shouldn't normally need to depend on this crate directly.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, spanned::Spanned, Error, Expr, ItemFn};
+
+/// Export a function so it can be called from a LabVIEW Call Library Function Node.
+///
+/// This expands to the `#[no_mangle] pub extern "C"` declaration that every
+/// exported function needs. The signature is kept exactly as written so it
+/// can be matched in the LabVIEW configuration.
+///
+/// A panic in an `extern "C"` function aborts the process rather than unwinding
+/// into LabVIEW. To return a value instead, set `on_panic` to the value to return
+/// and the body is run inside [`std::panic::catch_unwind`]. Pick a value LabVIEW
+/// can tell apart from success, such as an error code.
+///
+/// # Example
+///
+/// ```ignore
+/// use labview_interop::labview_export;
+///
+/// #[labview_export]
+/// fn add(a: f64, b: f64) -> f64 {
+///     a + b
+/// }
+///
+/// #[labview_export(on_panic = -1)]
+/// fn checked_divide(a: i32, b: i32) -> i32 {
+///     a.checked_div(b).expect("divide by zero")
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn labview_export(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut on_panic: Option<Expr> = None;
+    let attribute_parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("on_panic") {
+            on_panic = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported labview_export argument, expected `on_panic`"))
+        }
+    });
+    parse_macro_input!(attr with attribute_parser);
+
+    let function = parse_macro_input!(item as ItemFn);
+    match expand_export(function, on_panic) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand_export(
+    mut function: ItemFn,
+    on_panic: Option<Expr>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let signature = &function.sig;
+    if !signature.generics.params.is_empty() {
+        return Err(Error::new(
+            signature.generics.span(),
+            "exported functions cannot be generic",
+        ));
+    }
+    if let Some(asyncness) = &signature.asyncness {
+        return Err(Error::new(
+            asyncness.span(),
+            "exported functions cannot be async",
+        ));
+    }
+    if let Some(variadic) = &signature.variadic {
+        return Err(Error::new(
+            variadic.span(),
+            "exported functions cannot be variadic",
+        ));
+    }
+
+    function.vis = parse_quote!(pub);
+    function.sig.abi = Some(parse_quote!(extern "C"));
+
+    if let Some(on_panic) = on_panic {
+        let body = &function.block;
+        function.block = parse_quote!({
+            match ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(move || #body)) {
+                ::std::result::Result::Ok(value) => value,
+                ::std::result::Result::Err(_) => #on_panic,
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[no_mangle]
+        #function
+    })
+}
@@ -0,0 +1,50 @@
+use labview_interop_derive::labview_export;
+
+#[labview_export]
+fn add(a: f64, b: f64) -> f64 {
+    a + b
+}
+
+#[labview_export(on_panic = -1)]
+fn checked_divide(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        panic!("divide by zero");
+    }
+    a / b
+}
+
+#[labview_export]
+unsafe fn write_output(value: u32, output: *mut u32) {
+    *output = value;
+}
+
+#[labview_export]
+fn pass_through(pointer: *mut u32) -> *mut u32 {
+    pointer
+}
+
+#[test]
+fn test_exported_function_is_callable() {
+    let function: extern "C" fn(f64, f64) -> f64 = add;
+    assert_eq!(function(1.5, 2.0), 3.5);
+}
+
+#[test]
+fn test_exported_function_returns_on_panic_value() {
+    assert_eq!(checked_divide(10, 2), 5);
+    assert_eq!(checked_divide(10, 0), -1);
+}
+
+#[test]
+fn test_exported_unsafe_function() {
+    let mut output = 0u32;
+    unsafe { write_output(42, &mut output) };
+    assert_eq!(output, 42);
+}
+
+#[test]
+fn test_exported_function_returning_pointer() {
+    let mut value = 5u32;
+    let function: extern "C" fn(*mut u32) -> *mut u32 = pass_through;
+    assert_eq!(function(&mut value), &mut value as *mut u32);
+}
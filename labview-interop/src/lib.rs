@@ -1,5 +1,10 @@
 pub mod memory;
+pub mod prelude;
+pub mod session;
 pub mod types;
 
+#[cfg(feature = "derive")]
+pub use labview_interop_derive::labview_export;
+
 #[cfg(test)]
 mod tests {}
@@ -3,10 +3,80 @@
 //!
 //! todo: get to reference without panics.
 
+use std::pin::Pin;
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum MemoryError {
+    #[error("The handle or the data it points to is null.")]
+    InvalidHandle,
+}
+
 /// A pointer from LabVIEW for the data.
 #[repr(transparent)]
 pub struct UPtr<T>(*mut T);
 
+impl<T> UPtr<T> {
+    /// Read a field of the data behind the pointer at a byte offset from the start of the data.
+    ///
+    /// This is useful for clusters passed as pointers (such as "handles by value")
+    /// where the 32 bit packing prevents taking a reference to a field. Use
+    /// [`std::mem::offset_of`] to get the offset of the field.
+    ///
+    /// Returns [`None`] if the pointer is null.
+    ///
+    /// The value is a bitwise copy of the field so `U` must not need to be dropped,
+    /// which is checked at compile time.
+    ///
+    /// # Example
+    /// ```
+    /// use labview_interop::labview_layout;
+    /// use labview_interop::memory::UPtr;
+    /// use std::mem::offset_of;
+    ///
+    /// labview_layout!(
+    ///     pub struct Cluster {
+    ///         status: u8,
+    ///         code: i32,
+    ///     }
+    /// );
+    ///
+    /// fn read_code(cluster: UPtr<Cluster>) -> Option<i32> {
+    ///     // Safety: The offset is from the cluster definition.
+    ///     unsafe { cluster.read_field(offset_of!(Cluster, code)) }
+    /// }
+    /// ```
+    ///
+    /// Reading a type which needs to be dropped is rejected at compile time.
+    /// ```compile_fail
+    /// use labview_interop::memory::UPtr;
+    ///
+    /// unsafe fn read_name(cluster: UPtr<u8>) -> Option<String> {
+    ///     cluster.read_field(0)
+    /// }
+    /// # let _reader: unsafe fn(UPtr<u8>) -> Option<String> = read_name;
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// * The pointer must be null or point to an initialized instance of T.
+    /// * `offset` must be the offset of a field of type `U` within T.
+    pub unsafe fn read_field<U>(&self, offset: usize) -> Option<U> {
+        const {
+            assert!(
+                !std::mem::needs_drop::<U>(),
+                "read_field cannot read types which need to be dropped"
+            )
+        };
+        if self.0.is_null() {
+            return None;
+        }
+        let field_ptr = (self.0 as *const u8).add(offset) as *const U;
+        Some(std::ptr::read_unaligned(field_ptr))
+    }
+}
+
 /// A handle from LabVIEW for the data.
 ///
 /// A handle is a double pointer so the underlying
@@ -25,7 +95,7 @@ impl<T> UHandle<T> {
     ///* The pointer must point to an initialized instance of T.
     ///* You must enforce Rust's aliasing rules, since the returned lifetime 'a is arbitrarily chosen and does not necessarily reflect the actual lifetime of the data. In particular, while this reference exists, the memory the pointer points to must not get mutated (except inside UnsafeCell).
     pub unsafe fn as_ref(&self) -> Option<&T> {
-        self.0.as_ref().map(|ptr| ptr.as_ref()).flatten()
+        self.0.as_ref().and_then(|ptr| ptr.as_ref())
     }
 
     /// Get a mutable reference to the internal type.
@@ -38,7 +108,287 @@ impl<T> UHandle<T> {
     /// * It must be “dereferenceable” in the sense defined in the module documentation.
     /// * The pointer must point to an initialized instance of T.
     /// * You must enforce Rust’s aliasing rules, since the returned lifetime 'a is arbitrarily chosen and does not necessarily reflect the actual lifetime of the data. In particular, while this reference exists, the memory the pointer points to must not get accessed (read or written) through any other pointer.
+    #[allow(clippy::mut_from_ref)]
     pub unsafe fn as_mut(&self) -> Option<&mut T> {
-        self.0.as_ref().map(|ptr| ptr.as_mut()).flatten()
+        self.0.as_ref().and_then(|ptr| ptr.as_mut())
+    }
+
+    /// Copy the bytes of the internal type into `buf`.
+    ///
+    /// This copies up to `buf.len()` bytes and returns the number of bytes copied
+    /// so large data can be read without allocating a new buffer.
+    ///
+    /// This copies `size_of::<T>()` bytes at most. For an [`LVArray`](crate::types::LVArray)
+    /// handle that is only the header and the first element, and on 64 bit targets it
+    /// includes the padding after the header. Use
+    /// [`LVArrayHandle::read_data_into`](crate::types::LVArrayHandle::read_data_into)
+    /// to read array data instead.
+    ///
+    /// # Safety
+    ///
+    /// The same rules as [`UHandle::as_ref`] apply. Additionally T should not contain
+    /// any padding bytes as these are uninitialized.
+    pub unsafe fn read_into(&self, buf: &mut [u8]) -> Result<usize, MemoryError> {
+        let data = self.as_ref().ok_or(MemoryError::InvalidHandle)?;
+        let count = std::mem::size_of::<T>().min(buf.len());
+        std::ptr::copy_nonoverlapping(data as *const T as *const u8, buf.as_mut_ptr(), count);
+        Ok(count)
+    }
+
+    /// Describe the handle for logging and debugging.
+    ///
+    /// This reports whether the handle and the data pointer are null along with
+    /// their addresses and `size_of::<T>()`. It doesn't check the handle is valid
+    /// or read the data itself. For an [`LVArray`](crate::types::LVArray) the size
+    /// of `T` is the header and one element, not the size of the data.
+    ///
+    /// # Safety
+    ///
+    /// The handle must either be null or point to a readable data pointer.
+    /// The data pointer is never dereferenced so may be invalid.
+    pub unsafe fn describe(&self) -> String {
+        if self.0.is_null() {
+            return "null handle".to_string();
+        }
+        let data = *self.0;
+        if data.is_null() {
+            format!("handle @{:p}, null data pointer", self.0)
+        } else {
+            format!(
+                "non-null handle @{:p}, data @{:p}, sizeof T: {} bytes",
+                self.0,
+                data,
+                std::mem::size_of::<T>()
+            )
+        }
+    }
+
+    /// Check the handle once and return a [`ValidatedHandle`] which can access
+    /// the data without any further checks.
+    ///
+    /// Returns [`MemoryError::InvalidHandle`] if the handle or the data is null.
+    ///
+    /// # Safety
+    ///
+    /// The same rules as [`UHandle::as_mut`] apply for the lifetime of the
+    /// [`ValidatedHandle`]. The check is only made here so LabVIEW must not free
+    /// or resize the handle while the [`ValidatedHandle`] exists, which is normally
+    /// true for the duration of a call from LabVIEW.
+    pub unsafe fn validate(&mut self) -> Result<ValidatedHandle<'_, T>, MemoryError> {
+        if self.as_ref().is_some() {
+            Ok(ValidatedHandle(self))
+        } else {
+            Err(MemoryError::InvalidHandle)
+        }
+    }
+
+    /// Get a [`PinnedHandle`] to access the data through [`Pin`].
+    ///
+    /// This is for address sensitive data such as clusters which reference their own
+    /// fields, where moving the data out of the handle would break it.
+    ///
+    /// Returns [`None`] if the handle or the data is null.
+    ///
+    /// # Example
+    /// ```
+    /// use labview_interop::memory::UHandle;
+    ///
+    /// fn increment(handle: &mut UHandle<u32>) {
+    ///     // Safety: LabVIEW doesn't move the data during this call.
+    ///     if let Some(mut pinned) = unsafe { handle.pin() } {
+    ///         *pinned.as_mut() += 1;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The same rules as [`UHandle::as_mut`] apply. [`Pin`] also requires that the
+    /// data is never moved or freed from this point until `T` is dropped, not just
+    /// while the [`PinnedHandle`] exists.
+    ///
+    /// LabVIEW can move the data when it resizes the handle or free it without running
+    /// any Rust drop code, so this can't be relied on in general. For a `T` which is
+    /// not [`Unpin`] you must be sure the handle is never resized or disposed of while
+    /// `T` is alive, for example because it is only used within a single call and
+    /// `T` is dropped in place before returning.
+    pub unsafe fn pin(&mut self) -> Option<PinnedHandle<'_, T>> {
+        if self.as_ref().is_some() {
+            Some(PinnedHandle(self))
+        } else {
+            None
+        }
+    }
+}
+
+/// A handle which has been checked once so the data can be accessed without checking again.
+///
+/// Created by [`UHandle::validate`].
+pub struct ValidatedHandle<'a, T>(&'a mut UHandle<T>);
+
+impl<T> AsRef<T> for ValidatedHandle<'_, T> {
+    fn as_ref(&self) -> &T {
+        // Safety: The handle was checked when this was created and the caller of
+        // [`UHandle::validate`] guarantees it remains valid.
+        unsafe { &**(self.0).0 }
+    }
+}
+
+impl<T> AsMut<T> for ValidatedHandle<'_, T> {
+    fn as_mut(&mut self) -> &mut T {
+        // Safety: The handle was checked when this was created and the caller of
+        // [`UHandle::validate`] guarantees it remains valid.
+        unsafe { &mut **(self.0).0 }
+    }
+}
+
+/// A handle with the data accessed through [`Pin`] so it can't be moved out of the handle.
+///
+/// Created by [`UHandle::pin`], see its safety section for the guarantees this relies on.
+pub struct PinnedHandle<'a, T>(&'a mut UHandle<T>);
+
+impl<T> PinnedHandle<'_, T> {
+    /// Get a pinned reference to the data.
+    pub fn as_ref(&self) -> Pin<&T> {
+        // Safety: The handle was checked when this was created and the caller of
+        // [`UHandle::pin`] guarantees the data isn't moved.
+        unsafe { Pin::new_unchecked(&**(self.0).0) }
+    }
+
+    /// Get a pinned mutable reference to the data.
+    pub fn as_mut(&mut self) -> Pin<&mut T> {
+        // Safety: The handle was checked when this was created and the caller of
+        // [`UHandle::pin`] guarantees the data isn't moved.
+        unsafe { Pin::new_unchecked(&mut **(self.0).0) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uptr_read_field() {
+        crate::labview_layout!(
+            struct Cluster {
+                one: u8,
+                two: f64,
+            }
+        );
+
+        let mut cluster = Cluster { one: 5, two: 2.5 };
+        let pointer = UPtr(&mut cluster as *mut Cluster);
+        unsafe {
+            let one: u8 = pointer
+                .read_field(std::mem::offset_of!(Cluster, one))
+                .unwrap();
+            let two: f64 = pointer
+                .read_field(std::mem::offset_of!(Cluster, two))
+                .unwrap();
+            assert_eq!(one, 5);
+            assert_eq!(two, 2.5);
+        }
+    }
+
+    #[test]
+    fn test_uptr_read_field_null() {
+        let pointer = UPtr::<u64>(std::ptr::null_mut());
+        let value: Option<u32> = unsafe { pointer.read_field(0) };
+        assert!(value.is_none());
+    }
+
+    #[test]
+    fn test_pinned_handle() {
+        let mut value = 5u32;
+        let mut pointer = &mut value as *mut u32;
+        let mut handle = UHandle(&mut pointer);
+
+        let mut pinned = unsafe { handle.pin() }.unwrap();
+        *pinned.as_mut() += 1;
+        assert_eq!(*pinned.as_ref(), 6);
+        assert_eq!(&*pinned.as_ref() as *const u32, &value as *const u32);
+        assert_eq!(value, 6);
+    }
+
+    #[test]
+    fn test_describe_handle() {
+        let mut value = 5u32;
+        let mut pointer = &mut value as *mut u32;
+        let handle = UHandle(&mut pointer);
+        let description = unsafe { handle.describe() };
+        assert!(description.starts_with("non-null handle @0x"));
+        assert!(description.ends_with("sizeof T: 4 bytes"));
+
+        let mut null_pointer: *mut u32 = std::ptr::null_mut();
+        let handle = UHandle(&mut null_pointer);
+        let description = unsafe { handle.describe() };
+        assert!(description.starts_with("handle @0x"));
+        assert!(description.ends_with("null data pointer"));
+
+        let handle = UHandle::<u32>(std::ptr::null_mut());
+        assert_eq!(unsafe { handle.describe() }, "null handle");
+    }
+
+    #[test]
+    fn test_validated_handle() {
+        let mut value = 5u32;
+        let mut pointer = &mut value as *mut u32;
+        let mut handle = UHandle(&mut pointer);
+
+        let mut validated = unsafe { handle.validate() }.unwrap();
+        *validated.as_mut() += 1;
+        assert_eq!(*validated.as_ref(), 6);
+        assert_eq!(value, 6);
+    }
+
+    #[test]
+    fn test_validate_null_handle() {
+        let mut handle = UHandle::<u32>(std::ptr::null_mut());
+        assert!(matches!(
+            unsafe { handle.validate() },
+            Err(MemoryError::InvalidHandle)
+        ));
+
+        let mut null_pointer: *mut u32 = std::ptr::null_mut();
+        let mut handle = UHandle(&mut null_pointer);
+        assert!(unsafe { handle.validate() }.is_err());
+    }
+
+    #[test]
+    fn test_pin_null_handle() {
+        let mut handle = UHandle::<u32>(std::ptr::null_mut());
+        assert!(unsafe { handle.pin() }.is_none());
+    }
+
+    #[test]
+    fn test_read_into_smaller_buffer() {
+        let mut value = [1u8, 2, 3, 4, 5, 6];
+        let mut pointer = &mut value as *mut [u8; 6];
+        let handle = UHandle(&mut pointer);
+
+        let mut buf = [0u8; 4];
+        let count = unsafe { handle.read_into(&mut buf) }.unwrap();
+        assert_eq!(count, 4);
+        assert_eq!(buf, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_read_into_larger_buffer() {
+        let mut value = [1u8, 2, 3, 4, 5, 6];
+        let mut pointer = &mut value as *mut [u8; 6];
+        let handle = UHandle(&mut pointer);
+
+        let mut buf = [0u8; 8];
+        let count = unsafe { handle.read_into(&mut buf) }.unwrap();
+        assert_eq!(count, 6);
+        assert_eq!(buf, [1, 2, 3, 4, 5, 6, 0, 0]);
+    }
+
+    #[test]
+    fn test_read_into_null_handle() {
+        let handle = UHandle::<u32>(std::ptr::null_mut());
+        let mut buf = [0u8; 4];
+        let result = unsafe { handle.read_into(&mut buf) };
+        assert!(matches!(result, Err(MemoryError::InvalidHandle)));
     }
 }
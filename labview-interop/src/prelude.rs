@@ -0,0 +1,30 @@
+//! The prelude re-exports the commonly used types so they
+//! can be imported together.
+//!
+//! # Example
+//! ```
+//! use labview_interop::prelude::*;
+//!
+//! labview_layout!(
+//!     pub struct Measurement {
+//!         time: LVTime,
+//!         data: LVArrayHandle<1, f64>,
+//!     }
+//! );
+//!
+//! fn first_value(data: &LVArray<1, f64>) -> Option<f64> {
+//!     if data.get_data_size() > 0 {
+//!         // Safety: We have checked there is at least one element.
+//!         Some(unsafe { data.get_value_unchecked(0) })
+//!     } else {
+//!         None
+//!     }
+//! }
+//! ```
+
+pub use crate::memory::{UHandle, UPtr};
+pub use crate::types::{LVArray, LVArrayHandle, LVTime, LVVariant, Waveform};
+pub use crate::{labview_cluster, labview_layout};
+
+#[cfg(feature = "derive")]
+pub use crate::labview_export;
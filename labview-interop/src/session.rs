@@ -0,0 +1,132 @@
+//! The session module provides storage for Rust state which needs
+//! to persist between calls from LabVIEW.
+//!
+//! Functions called repeatedly from the same VI often need to keep
+//! state between calls. This provides a thread-safe registry where
+//! values are stored against a key supplied by the caller, such as
+//! a refnum or an ID generated on the LabVIEW side.
+//!
+//! Values are kept until they are removed with [`remove`] so make
+//! sure there is a matching cleanup call from LabVIEW.
+//!
+//! # Example
+//! ```
+//! use labview_interop::session;
+//!
+//! // First call stores the state.
+//! session::store(1, Vec::<u8>::new());
+//!
+//! // Later calls update it.
+//! session::with_mut(1, |buffer: &mut Vec<u8>| buffer.extend_from_slice(&[1, 2, 3]));
+//! assert_eq!(session::get::<Vec<u8>>(1), Some(vec![1, 2, 3]));
+//!
+//! // Cleanup call removes it.
+//! assert!(session::remove(1));
+//! ```
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard, OnceLock, PoisonError};
+
+type Registry = HashMap<u64, Box<dyn Any + Send>>;
+
+fn registry() -> MutexGuard<'static, Registry> {
+    static REGISTRY: OnceLock<Mutex<Registry>> = OnceLock::new();
+    REGISTRY
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        // A panic while the lock was held cannot leave the map itself in an invalid state.
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Store a value against the key, replacing any existing value.
+pub fn store<T: Any + Send>(key: u64, value: T) {
+    registry().insert(key, Box::new(value));
+}
+
+/// Get a copy of the value stored against the key.
+///
+/// Returns [`None`] if there is no value or it is not of type T.
+pub fn get<T: Any + Clone>(key: u64) -> Option<T> {
+    registry()
+        .get(&key)
+        .and_then(|value| value.downcast_ref::<T>())
+        .cloned()
+}
+
+/// Run a function with mutable access to the value stored against the key.
+///
+/// Returns [`None`] if there is no value or it is not of type T.
+///
+/// The registry is locked while the function runs so it must not call
+/// back into this module or it will deadlock.
+pub fn with_mut<T: Any, R>(key: u64, function: impl FnOnce(&mut T) -> R) -> Option<R> {
+    registry()
+        .get_mut(&key)
+        .and_then(|value| value.downcast_mut::<T>())
+        .map(function)
+}
+
+/// Remove the value stored against the key.
+///
+/// Returns true if there was a value to remove.
+pub fn remove(key: u64) -> bool {
+    registry().remove(&key).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The registry is shared so each test uses its own keys.
+
+    #[test]
+    fn test_store_and_get() {
+        store(100, 42u32);
+        assert_eq!(get::<u32>(100), Some(42));
+        store(100, 43u32);
+        assert_eq!(get::<u32>(100), Some(43));
+        assert!(remove(100));
+        assert_eq!(get::<u32>(100), None);
+        assert!(!remove(100));
+    }
+
+    #[test]
+    fn test_get_wrong_type() {
+        store(101, String::from("state"));
+        assert_eq!(get::<u32>(101), None);
+        assert_eq!(get::<String>(101), Some(String::from("state")));
+        remove(101);
+    }
+
+    #[test]
+    fn test_state_across_calls() {
+        fn call(key: u64, data: &[u8]) -> usize {
+            with_mut(key, |buffer: &mut Vec<u8>| {
+                buffer.extend_from_slice(data);
+                buffer.len()
+            })
+            .unwrap()
+        }
+
+        store(102, Vec::<u8>::new());
+        assert_eq!(call(102, &[1, 2]), 2);
+        assert_eq!(call(102, &[3]), 3);
+        assert_eq!(get::<Vec<u8>>(102), Some(vec![1, 2, 3]));
+        remove(102);
+    }
+
+    #[test]
+    fn test_store_from_threads() {
+        let handles: Vec<_> = (0..4u64)
+            .map(|index| std::thread::spawn(move || store(200 + index, index)))
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        for index in 0..4u64 {
+            assert_eq!(get::<u64>(200 + index), Some(index));
+            remove(200 + index);
+        }
+    }
+}
@@ -2,8 +2,62 @@
 //!
 //! todo: empty array can be an null handle. Detect and use.
 
+use std::borrow::Cow;
+
+use thiserror::Error;
+
 use crate::labview_layout;
-use crate::memory::UHandle;
+use crate::memory::{MemoryError, UHandle};
+
+#[derive(Debug, Error)]
+pub enum LVArrayError {
+    #[error("Index {index} is out of range for an array with {size} elements.")]
+    IndexOutOfRange { index: usize, size: usize },
+    #[error("Expected {expected} dimensions but the shape has {actual}.")]
+    DimensionCountMismatch { expected: usize, actual: usize },
+    #[error("The array size overflows the supported size.")]
+    SizeOverflow,
+    #[error("Expected array dimensions {expected:?} but found {actual:?}.")]
+    DimensionMismatch {
+        expected: Vec<i32>,
+        actual: Vec<i32>,
+    },
+    #[error("Cannot split an array of {size} elements into rows of {columns} columns.")]
+    InvalidColumnCount { size: usize, columns: usize },
+}
+
+/// Calculate the total number of elements from a runtime shape.
+///
+/// Returns an error if the total overflows a [`usize`].
+pub fn total_from_shape(shape: &[usize]) -> Result<usize, LVArrayError> {
+    shape.iter().try_fold(1usize, |total, &dim| {
+        total.checked_mul(dim).ok_or(LVArrayError::SizeOverflow)
+    })
+}
+
+/// Convert a runtime shape into the dimension sizes used by an array with `D` dimensions.
+///
+/// Returns an error if the shape doesn't have `D` dimensions or if any dimension
+/// or the total element count is too large for LabVIEW.
+pub fn dims_from_shape<const D: usize>(shape: &[usize]) -> Result<[i32; D], LVArrayError> {
+    if shape.len() != D {
+        return Err(LVArrayError::DimensionCountMismatch {
+            expected: D,
+            actual: shape.len(),
+        });
+    }
+
+    let total = total_from_shape(shape)?;
+    if i32::try_from(total).is_err() {
+        return Err(LVArrayError::SizeOverflow);
+    }
+
+    let mut dims = [0i32; D];
+    for (dim, &size) in dims.iter_mut().zip(shape) {
+        *dim = i32::try_from(size).map_err(|_| LVArrayError::SizeOverflow)?;
+    }
+    Ok(dims)
+}
 
 labview_layout!(
     /// Internal LabVIEW array representation.
@@ -15,6 +69,67 @@ labview_layout!(
     }
 );
 
+impl<const D: usize, T> LVArray<D, T> {
+    /// Get the size of each dimension.
+    /// Works with the packed structures found in the 32 bit interface.
+    pub fn dimension_sizes(&self) -> [i32; D] {
+        // Safety: the pointer is to the array in this structure.
+        unsafe { std::ptr::read_unaligned(std::ptr::addr_of!(self.dim_sizes)) }
+    }
+
+    /// Check the array has the expected dimension sizes.
+    ///
+    /// Use this as a guard at the start of functions which require a specific shape.
+    pub fn expect_shape(&self, shape: [i32; D]) -> Result<(), LVArrayError> {
+        let actual = self.dimension_sizes();
+        if actual == shape {
+            Ok(())
+        } else {
+            Err(LVArrayError::DimensionMismatch {
+                expected: shape.to_vec(),
+                actual: actual.to_vec(),
+            })
+        }
+    }
+
+    /// Get the total number of elements from the dimension sizes without trusting them.
+    ///
+    /// Negative sizes count as zero and an overflowing product saturates to [`usize::MAX`]
+    /// so a corrupt header can't cause a panic.
+    fn saturating_element_count(&self) -> usize {
+        self.dimension_sizes()
+            .iter()
+            .map(|&size| size.max(0) as usize)
+            .try_fold(1usize, |count, size| count.checked_mul(size))
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Get the total size of the array in bytes, including the dimension sizes.
+    ///
+    /// This accounts for the padding between the dimension sizes and the data
+    /// on 64 bit targets which isn't present in the packed 32 bit layout.
+    ///
+    /// Negative dimension sizes count as zero and the result saturates to
+    /// [`usize::MAX`] rather than overflowing on a corrupt header.
+    pub fn byte_size(&self) -> usize {
+        self.saturating_element_count()
+            .saturating_mul(std::mem::size_of::<T>())
+            .saturating_add(std::mem::offset_of!(Self, data))
+    }
+
+    /// Set all the dimension sizes to zero so the array is logically empty.
+    ///
+    /// This doesn't free the data so the existing allocation can be reused.
+    /// It works with the packed structures found in the 32 bit interface.
+    pub fn clear(&mut self) {
+        for index in 0..D {
+            let element_ptr = std::ptr::addr_of_mut!(self.dim_sizes[index]);
+            // Safety: the indexes must be in range due to the const generic value.
+            unsafe { std::ptr::write_unaligned(element_ptr, 0) };
+        }
+    }
+}
+
 ///implement a basic, unsafe API that works for packed usage on 32 bit targets.
 ///
 /// It is copy only as we must copy out of the pointers.
@@ -43,16 +158,145 @@ impl<const D: usize, T: Copy> LVArray<D, T> {
     /// If the index is out of the range then it is undefined behaviour.
     pub unsafe fn get_value_unchecked(&self, index: usize) -> T {
         let data_ptr = std::ptr::addr_of!(self.data);
-        let element_ptr = data_ptr.offset(index as isize);
+        let element_ptr = data_ptr.add(index);
         std::ptr::read_unaligned(element_ptr)
     }
+
+    /// Get the data as a [`Cow`] slice so the same code works on all targets.
+    ///
+    /// On 64 bit targets this borrows the data without copying. On 32 bit targets
+    /// the packed structure means we cannot borrow a slice so this is an owned copy.
+    pub fn as_cow_slice(&self) -> Cow<'_, [T]> {
+        #[cfg(target_pointer_width = "64")]
+        {
+            Cow::Borrowed(self.data_as_slice())
+        }
+        #[cfg(target_pointer_width = "32")]
+        {
+            let values = (0..self.get_data_size())
+                // Safety: The indexes are in the range of the dimensions.
+                .map(|index| unsafe { self.get_value_unchecked(index) })
+                .collect();
+            Cow::Owned(values)
+        }
+    }
+
+    /// Copy the data into a [`Vec`] which has no dependency on LabVIEW memory.
+    ///
+    /// Use this to keep the data after the handle is released back to LabVIEW.
+    /// Multi-dimensional arrays are flattened in row-major order.
+    pub fn to_vec(&self) -> Vec<T> {
+        self.as_cow_slice().into_owned()
+    }
+
+    /// Set the value directly in the array. This is an unsafe method used on
+    /// 32 bit targets where the packed structure means we cannot access a slice.
+    ///
+    /// On 64 bit targets use [`LVArray::data_as_slice_mut`] instead.
+    ///
+    /// # Safety
+    ///
+    /// If the index is out of the range then it is undefined behaviour.
+    pub unsafe fn set_value_unchecked(&mut self, index: usize, value: T) {
+        let data_ptr = std::ptr::addr_of_mut!(self.data);
+        let element_ptr = data_ptr.add(index);
+        std::ptr::write_unaligned(element_ptr, value)
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+impl<const D: usize, T: Copy> LVArray<D, T> {
+    /// Iterate over the elements mutably.
+    ///
+    /// The packed structure on 32 bit targets means we cannot get a reference to
+    /// the elements so this yields an [`ElementGuard`] holding a copy of each element
+    /// which is written back to the array when the guard is dropped.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: std::ptr::addr_of_mut!(self.data),
+            remaining: self.get_data_size(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Mutable iterator over a packed array on 32 bit targets.
+///
+/// Created by [`LVArray::iter_mut`].
+#[cfg(target_pointer_width = "32")]
+pub struct IterMut<'a, T: Copy> {
+    next: *mut T,
+    remaining: usize,
+    _marker: std::marker::PhantomData<&'a mut T>,
+}
+
+#[cfg(target_pointer_width = "32")]
+impl<'a, T: Copy> Iterator for IterMut<'a, T> {
+    type Item = ElementGuard<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let element = self.next;
+        // Safety: Each element is only visited once so the guards never overlap
+        // and the remaining count keeps us in the bounds set by the dimensions.
+        unsafe {
+            self.next = self.next.add(1);
+            self.remaining -= 1;
+            Some(ElementGuard {
+                element,
+                value: std::ptr::read_unaligned(element),
+                _marker: std::marker::PhantomData,
+            })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+/// A copy of an element in a packed array which is written back to the array on drop.
+///
+/// This derefs to the element value so can be used like a `&mut T`.
+#[cfg(target_pointer_width = "32")]
+pub struct ElementGuard<'a, T: Copy> {
+    element: *mut T,
+    value: T,
+    _marker: std::marker::PhantomData<&'a mut T>,
+}
+
+#[cfg(target_pointer_width = "32")]
+impl<T: Copy> std::ops::Deref for ElementGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+impl<T: Copy> std::ops::DerefMut for ElementGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+impl<T: Copy> Drop for ElementGuard<'_, T> {
+    fn drop(&mut self) {
+        // Safety: The pointer was created from a valid element by the iterator.
+        unsafe { std::ptr::write_unaligned(self.element, self.value) }
+    }
 }
 
 #[cfg(target_pointer_width = "64")]
 impl<const D: usize, T> LVArray<D, T> {
     /// Get the total number of elements in the array across all dimensions.
     pub fn element_count(&self) -> usize {
-        let size = self.dim_sizes.iter().fold(1, |size, dim| size * dim);
+        let size: i32 = self.dim_sizes.iter().product();
         size as usize
     }
 
@@ -79,7 +323,1074 @@ impl<const D: usize, T> LVArray<D, T> {
         // Safety: Dimensions are set by LabVIEW to be valid.
         unsafe { std::slice::from_raw_parts_mut(&mut self.data, size) }
     }
+
+    /// Copy the array into an existing [`ndarray`] view.
+    ///
+    /// The data is in row-major order so the view must have the same shape as
+    /// the LabVIEW array or an error is returned.
+    #[cfg(feature = "ndarray")]
+    pub fn copy_to_ndarray<Dim: ndarray::Dimension>(
+        &self,
+        out: &mut ndarray::ArrayViewMut<T, Dim>,
+    ) -> Result<(), LVArrayError>
+    where
+        T: Copy,
+    {
+        let dims = self.dimension_sizes();
+        let shape_matches = out.ndim() == D
+            && out
+                .shape()
+                .iter()
+                .zip(dims.iter())
+                .all(|(&out_size, &size)| out_size as i64 == size as i64);
+        if !shape_matches {
+            return Err(LVArrayError::DimensionMismatch {
+                expected: out.shape().iter().map(|&size| size as i32).collect(),
+                actual: dims.to_vec(),
+            });
+        }
+
+        let view = ndarray::ArrayView::from_shape(out.raw_dim(), self.data_as_slice())
+            .map_err(|_| LVArrayError::SizeOverflow)?;
+        out.assign(&view);
+        Ok(())
+    }
+
+    /// Get the data component as a slice of at most `max` elements.
+    ///
+    /// This lets functions limit how much they read from an untrusted array
+    /// rather than trusting the dimension sizes.
+    pub fn data_as_slice_capped(&self, max: usize) -> &[T] {
+        let size = self.saturating_element_count().min(max);
+        // Safety: The size is no more than the dimensions which LabVIEW sets to be valid.
+        unsafe { std::slice::from_raw_parts(&self.data, size) }
+    }
+
+    /// Get the number of elements that can be safely read given the size of the
+    /// allocation for the whole array, including the dimension sizes.
+    ///
+    /// This is the smaller of the element count from the dimension sizes and the number
+    /// of elements that fit in the allocation so a corrupt dimension header cannot cause
+    /// a read beyond the allocation. Negative or overflowing dimension sizes are handled
+    /// without panicking.
+    pub fn safe_element_count(&self, allocated_bytes: usize) -> usize {
+        let element_count = self.saturating_element_count();
+        let element_size = std::mem::size_of::<T>();
+        if element_size == 0 {
+            return element_count;
+        }
+        let data_bytes = allocated_bytes.saturating_sub(std::mem::offset_of!(Self, data));
+        element_count.min(data_bytes / element_size)
+    }
+
+    /// Get the data component as a slice limited to the allocation size.
+    ///
+    /// `allocated_bytes` is the size of the allocation for the whole array, including
+    /// the dimension sizes. See [`LVArray::safe_element_count`].
+    pub fn safe_data_as_slice(&self, allocated_bytes: usize) -> &[T] {
+        let size = self.safe_element_count(allocated_bytes);
+        // Safety: The size is limited to the allocation size.
+        unsafe { std::slice::from_raw_parts(&self.data, size) }
+    }
+
+    /// Iterate over the elements in row-major order, the order they are stored in memory.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data_as_slice().iter()
+    }
+
+    /// Iterate over the elements in row-major order along with the index
+    /// of each element in every dimension.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ([usize; D], &T)> + '_ {
+        let dims = self.dimension_sizes().map(|size| size as usize);
+        self.iter().enumerate().map(move |(offset, value)| {
+            let mut index = [0usize; D];
+            let mut remaining = offset;
+            // The last dimension is contiguous so work back from it.
+            for (position, &size) in index.iter_mut().zip(dims.iter()).rev() {
+                *position = remaining % size;
+                remaining /= size;
+            }
+            (index, value)
+        })
+    }
+
+    /// Calculate the offset into the data of an element from its index in
+    /// each dimension, or `None` if any index is out of range.
+    ///
+    /// LabVIEW stores arrays in row-major order so the last dimension is contiguous.
+    fn offset_of_index(&self, index: [usize; D]) -> Option<usize> {
+        let dims = self.dimension_sizes();
+        let mut offset = 0usize;
+        for (&position, &size) in index.iter().zip(dims.iter()) {
+            let size = size as usize;
+            if position >= size {
+                return None;
+            }
+            offset = offset * size + position;
+        }
+        Some(offset)
+    }
+
+    /// Get a reference to the element at the index in each dimension.
+    ///
+    /// Returns `None` if any index is out of range.
+    pub fn get(&self, index: [usize; D]) -> Option<&T> {
+        let offset = self.offset_of_index(index)?;
+        self.data_as_slice().get(offset)
+    }
+
+    /// Get a mutable reference to the element at the index in each dimension.
+    ///
+    /// Returns `None` if any index is out of range.
+    pub fn get_mut(&mut self, index: [usize; D]) -> Option<&mut T> {
+        let offset = self.offset_of_index(index)?;
+        self.data_as_slice_mut().get_mut(offset)
+    }
+
+    /// Iterate over the elements mutably.
+    ///
+    /// On 32 bit targets this yields an `ElementGuard` instead of a reference
+    /// which derefs to the element in the same way.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data_as_slice_mut().iter_mut()
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl<T> LVArray<1, T> {
+    /// Add `value` to every element in place.
+    ///
+    /// This uses the standard Rust addition so integer overflow
+    /// panics in debug builds and wraps in release builds.
+    pub fn add_scalar(&mut self, value: T)
+    where
+        T: Copy + std::ops::AddAssign,
+    {
+        for element in self.data_as_slice_mut() {
+            *element += value;
+        }
+    }
+
+    /// Multiply every element by `value` in place.
+    ///
+    /// This uses the standard Rust multiplication so integer overflow
+    /// panics in debug builds and wraps in release builds.
+    pub fn mul_scalar(&mut self, value: T)
+    where
+        T: Copy + std::ops::MulAssign,
+    {
+        for element in self.data_as_slice_mut() {
+            *element *= value;
+        }
+    }
+
+    /// Iterate over the data in groups of `N` elements.
+    ///
+    /// This is useful for interleaved data such as XY pairs.
+    /// If the length isn't a multiple of `N` the trailing partial group is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    pub fn chunks_as<const N: usize>(&self) -> impl Iterator<Item = [T; N]> + '_
+    where
+        T: Copy,
+    {
+        self.data_as_slice()
+            .chunks_exact(N)
+            .map(|chunk| chunk.try_into().expect("chunks are exactly N long"))
+    }
+
+    /// Get the first element and the rest of the data.
+    ///
+    /// Returns [`None`] if the array is empty.
+    pub fn split_first(&self) -> Option<(&T, &[T])> {
+        self.data_as_slice().split_first()
+    }
+
+    /// Get the last element and the rest of the data.
+    ///
+    /// Returns [`None`] if the array is empty.
+    pub fn split_last(&self) -> Option<(&T, &[T])> {
+        self.data_as_slice().split_last()
+    }
+
+    /// Split the data into rows of `columns` elements to view it as a 2D array.
+    ///
+    /// Returns an error if `columns` is 0 or the length isn't a multiple of `columns`.
+    pub fn as_2d(&self, columns: usize) -> Result<Vec<&[T]>, LVArrayError> {
+        let data = self.data_as_slice();
+        if columns == 0 || !data.len().is_multiple_of(columns) {
+            return Err(LVArrayError::InvalidColumnCount {
+                size: data.len(),
+                columns,
+            });
+        }
+        Ok(data.chunks_exact(columns).collect())
+    }
+
+    /// Swap two elements in place.
+    ///
+    /// Returns an error if either index is out of range.
+    pub fn swap(&mut self, a: usize, b: usize) -> Result<(), LVArrayError> {
+        let data = self.data_as_slice_mut();
+        let size = data.len();
+        for index in [a, b] {
+            if index >= size {
+                return Err(LVArrayError::IndexOutOfRange { index, size });
+            }
+        }
+        data.swap(a, b);
+        Ok(())
+    }
+
+    /// Get mutable references to two different elements at once.
+    ///
+    /// Returns [`None`] if the indexes are equal or either is out of range.
+    pub fn get_two_mut(&mut self, a: usize, b: usize) -> Option<(&mut T, &mut T)> {
+        let data = self.data_as_slice_mut();
+        if a == b || a >= data.len() || b >= data.len() {
+            return None;
+        }
+        let (low, high) = (a.min(b), a.max(b));
+        let (start, end) = data.split_at_mut(high);
+        let (low_element, high_element) = (&mut start[low], &mut end[0]);
+        if a < b {
+            Some((low_element, high_element))
+        } else {
+            Some((high_element, low_element))
+        }
+    }
+
+    /// Rotate the elements in place so the element at index `n` becomes the first.
+    ///
+    /// `n` wraps around the length of the array so it can be larger than the array.
+    pub fn rotate_left(&mut self, n: usize) {
+        let data = self.data_as_slice_mut();
+        if !data.is_empty() {
+            let n = n % data.len();
+            data.rotate_left(n);
+        }
+    }
+
+    /// Rotate the elements in place so the last `n` elements move to the front.
+    ///
+    /// `n` wraps around the length of the array so it can be larger than the array.
+    pub fn rotate_right(&mut self, n: usize) {
+        let data = self.data_as_slice_mut();
+        if !data.is_empty() {
+            let n = n % data.len();
+            data.rotate_right(n);
+        }
+    }
+
+    /// Fill the array by repeating `pattern` from the start, wrapping as needed.
+    ///
+    /// If the pattern is longer than the array only the start of it is used.
+    /// An empty pattern leaves the array unchanged.
+    pub fn fill_pattern(&mut self, pattern: &[T])
+    where
+        T: Copy,
+    {
+        if pattern.is_empty() {
+            return;
+        }
+        for (value, &fill) in self
+            .data_as_slice_mut()
+            .iter_mut()
+            .zip(pattern.iter().cycle())
+        {
+            *value = fill;
+        }
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl LVArray<1, f64> {
+    /// Replace any NaN or infinite values with `replacement`.
+    ///
+    /// Returns the number of values replaced.
+    pub fn replace_non_finite(&mut self, replacement: f64) -> usize {
+        let mut replaced = 0;
+        for value in self.data_as_slice_mut() {
+            if !value.is_finite() {
+                *value = replacement;
+                replaced += 1;
+            }
+        }
+        replaced
+    }
+
+    /// Calculate the mean of the data.
+    ///
+    /// Returns [`None`] if the array is empty.
+    pub fn mean(&self) -> Option<f64> {
+        self.welford().map(|(_, mean, _)| mean)
+    }
+
+    /// Calculate the sample variance of the data.
+    ///
+    /// This uses Welford's algorithm so it is numerically stable for large arrays
+    /// with a large mean. It uses the sample (n - 1) weighting which is the default
+    /// in LabVIEW. Returns [`None`] if there are fewer than 2 elements.
+    pub fn variance(&self) -> Option<f64> {
+        match self.welford() {
+            Some((count, _, sum_of_squares)) if count > 1 => {
+                Some(sum_of_squares / (count - 1) as f64)
+            }
+            _ => None,
+        }
+    }
+
+    /// Calculate the sample standard deviation of the data.
+    ///
+    /// See [`LVArray::variance`] for the method used.
+    /// Returns [`None`] if there are fewer than 2 elements.
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Run Welford's algorithm returning the count, mean and sum of squared differences.
+    fn welford(&self) -> Option<(usize, f64, f64)> {
+        let data = self.data_as_slice();
+        if data.is_empty() {
+            return None;
+        }
+
+        let mut mean = 0.0;
+        let mut sum_of_squares = 0.0;
+        for (index, &value) in data.iter().enumerate() {
+            let delta = value - mean;
+            mean += delta / (index + 1) as f64;
+            sum_of_squares += delta * (value - mean);
+        }
+        Some((data.len(), mean, sum_of_squares))
+    }
+}
+
+impl LVArray<1, u8> {
+    /// Encode the array data as standard, padded base64 for text based protocols.
+    ///
+    /// Works with the packed structures found in the 32 bit interface.
+    #[cfg(feature = "base64")]
+    pub fn to_base64(&self) -> String {
+        use base64::Engine;
+        base64::engine::general_purpose::STANDARD.encode(self.as_cow_slice())
+    }
+
+    /// Compare the data with `other`, ignoring trailing zeros on either side.
+    ///
+    /// This is useful when comparing against fixed width, zero padded buffers.
+    /// Unlike comparing the slices directly, `[1, 2, 0]` and `[1, 2]` are equal.
+    ///
+    /// Works with the packed structures found in the 32 bit interface.
+    pub fn eq_trimmed(&self, other: &[u8]) -> bool {
+        fn trim_zeros(bytes: &[u8]) -> &[u8] {
+            let length = bytes
+                .iter()
+                .rposition(|&byte| byte != 0)
+                .map_or(0, |end| end + 1);
+            &bytes[..length]
+        }
+        trim_zeros(&self.as_cow_slice()) == trim_zeros(other)
+    }
+
+    /// Find the first position of `needle` in the array data.
+    ///
+    /// This uses an efficient substring search so is suitable for large buffers.
+    ///
+    /// Works with the packed structures found in the 32 bit interface.
+    pub fn find_subslice(&self, needle: &[u8]) -> Option<usize> {
+        memchr::memmem::find(&self.as_cow_slice(), needle)
+    }
+
+    /// Copy the array data into a [`bytes::Bytes`] buffer.
+    ///
+    /// Works with the packed structures found in the 32 bit interface.
+    #[cfg(feature = "bytes")]
+    pub fn to_bytes(&self) -> bytes::Bytes {
+        bytes::Bytes::copy_from_slice(&self.as_cow_slice())
+    }
+}
+
+#[cfg(all(feature = "half", target_pointer_width = "64"))]
+impl LVArray<1, u16> {
+    /// Get the data as 16 bit floats.
+    ///
+    /// LabVIEW has no half precision type so these are passed as U16 arrays
+    /// containing the raw bits.
+    pub fn as_f16_slice(&self) -> &[half::f16] {
+        use half::slice::HalfBitsSliceExt;
+        self.data_as_slice().reinterpret_cast()
+    }
+
+    /// Get the data as mutable 16 bit floats.
+    ///
+    /// LabVIEW has no half precision type so these are passed as U16 arrays
+    /// containing the raw bits.
+    pub fn as_f16_slice_mut(&mut self) -> &mut [half::f16] {
+        use half::slice::HalfBitsSliceExt;
+        self.data_as_slice_mut().reinterpret_cast_mut()
+    }
+}
+
+#[cfg(feature = "debug-util")]
+impl LVArray<1, u8> {
+    /// Format the data as a hex dump in the style of `xxd`.
+    ///
+    /// Each line shows the offset, 16 bytes in hex and the printable ASCII characters.
+    /// Works with the packed structures found in the 32 bit interface.
+    pub fn hex_dump(&self) -> String {
+        hex_dump(&self.as_cow_slice())
+    }
+}
+
+/// Format bytes as a hex dump in the style of `xxd`.
+#[cfg(feature = "debug-util")]
+fn hex_dump(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+
+    let mut output = String::new();
+    for (line, chunk) in bytes.chunks(16).enumerate() {
+        let mut hex = String::new();
+        for (index, byte) in chunk.iter().enumerate() {
+            if index > 0 && index % 2 == 0 {
+                hex.push(' ');
+            }
+            let _ = write!(hex, "{byte:02x}");
+        }
+        let ascii: String = chunk
+            .iter()
+            .map(|&byte| {
+                if byte.is_ascii_graphic() || byte == b' ' {
+                    byte as char
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        let _ = writeln!(output, "{:08x}: {hex:<39}  {ascii}", line * 16);
+    }
+    output
 }
 
 /// Definition of a handle to an array. Helper for FFI definitin.
 pub type LVArrayHandle<const D: usize, T> = UHandle<LVArray<D, T>>;
+
+impl<const D: usize, T: Copy> LVArrayHandle<D, T> {
+    /// Copy the bytes of the array data, starting `offset` bytes into the data, into `buf`.
+    ///
+    /// Unlike [`UHandle::read_into`] this copies the element data rather than the
+    /// array header so large arrays can be streamed in chunks by increasing `offset`
+    /// by the number of bytes copied each time. Returns the number of bytes copied
+    /// which is zero once the end of the data is reached.
+    ///
+    /// Works with the packed structures found in the 32 bit interface.
+    ///
+    /// # Safety
+    ///
+    /// The same rules as [`UHandle::as_ref`] apply. Additionally T should not contain
+    /// any padding bytes as these are uninitialized.
+    pub unsafe fn read_data_into(
+        &self,
+        offset: usize,
+        buf: &mut [u8],
+    ) -> Result<usize, MemoryError> {
+        let array = self.as_ref().ok_or(MemoryError::InvalidHandle)?;
+        let values = array.as_cow_slice();
+        let data_bytes = std::mem::size_of_val::<[T]>(&values);
+        let count = data_bytes.saturating_sub(offset).min(buf.len());
+        if count > 0 {
+            let data_ptr = (values.as_ptr() as *const u8).add(offset);
+            std::ptr::copy_nonoverlapping(data_ptr, buf.as_mut_ptr(), count);
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(all(feature = "bytemuck", target_pointer_width = "64"))]
+impl<T: bytemuck::Pod> LVArray<1, T> {
+    /// Get the data component as a byte slice without copying.
+    ///
+    /// This is useful for binary transport of numeric arrays. Use
+    /// [`bytemuck::cast_slice`] to reconstruct the elements from the bytes.
+    pub fn data_as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.data_as_slice())
+    }
+
+    /// Get the data component as a mutable byte slice without copying.
+    ///
+    /// The element count is not changed so the slice length is fixed
+    /// at the current size of the data.
+    pub fn data_as_bytes_mut(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(self.data_as_slice_mut())
+    }
+}
+
+#[cfg(test)]
+#[cfg(target_pointer_width = "64")]
+mod tests {
+    use super::*;
+
+    /// Matches the layout of [`LVArray`] with the data inline so
+    /// we can build arrays for testing without LabVIEW.
+    #[repr(C)]
+    struct TestArray<const D: usize, const N: usize, T> {
+        dim_sizes: [i32; D],
+        data: [T; N],
+    }
+
+    impl<const D: usize, const N: usize, T> TestArray<D, N, T> {
+        fn new(dim_sizes: [i32; D], data: [T; N]) -> Self {
+            Self { dim_sizes, data }
+        }
+
+        fn as_array(&self) -> &LVArray<D, T> {
+            // Safety: The layout matches LVArray and the data is inline.
+            unsafe { &*(self as *const Self as *const LVArray<D, T>) }
+        }
+
+        fn as_array_mut(&mut self) -> &mut LVArray<D, T> {
+            // Safety: The layout matches LVArray and the data is inline.
+            unsafe { &mut *(self as *mut Self as *mut LVArray<D, T>) }
+        }
+    }
+
+    #[test]
+    fn test_read_data_into_chunks() {
+        let mut test = TestArray::new([3], [1.5f64, -2.0, 4.25]);
+        let mut pointer = test.as_array_mut() as *mut LVArray<1, f64>;
+        let handle = UHandle(&mut pointer);
+
+        let mut bytes = Vec::new();
+        let mut buf = [0u8; 10];
+        loop {
+            let count = unsafe { handle.read_data_into(bytes.len(), &mut buf) }.unwrap();
+            if count == 0 {
+                break;
+            }
+            bytes.extend_from_slice(&buf[..count]);
+        }
+
+        let expected: Vec<u8> = [1.5f64, -2.0, 4.25]
+            .iter()
+            .flat_map(|value| value.to_ne_bytes())
+            .collect();
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn test_read_data_into_null_handle() {
+        let handle: LVArrayHandle<1, f64> = UHandle(std::ptr::null_mut());
+        let mut buf = [0u8; 8];
+        let result = unsafe { handle.read_data_into(0, &mut buf) };
+        assert!(matches!(result, Err(MemoryError::InvalidHandle)));
+    }
+
+    #[test]
+    fn test_element_count_and_slice() {
+        let test = TestArray::new([2, 3], [1u32, 2, 3, 4, 5, 6]);
+        let array = test.as_array();
+        assert_eq!(array.element_count(), 6);
+        assert_eq!(array.get_data_size(), 6);
+        assert_eq!(array.data_as_slice(), &[1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_data_as_slice_mut() {
+        let mut test = TestArray::new([3], [1i16, 2, 3]);
+        test.as_array_mut().data_as_slice_mut()[1] = 20;
+        assert_eq!(test.data, [1, 20, 3]);
+    }
+
+    #[test]
+    fn test_scale_and_offset() {
+        let mut test = TestArray::new([4], [1.0f64, 2.0, -3.0, 0.5]);
+        test.as_array_mut().mul_scalar(2.0);
+        test.as_array_mut().add_scalar(1.0);
+        assert_eq!(test.as_array().data_as_slice(), &[3.0, 5.0, -5.0, 2.0]);
+
+        let mut test = TestArray::new([3], [1i32, 2, 3]);
+        test.as_array_mut().mul_scalar(2);
+        test.as_array_mut().add_scalar(-1);
+        assert_eq!(test.as_array().data_as_slice(), &[1, 3, 5]);
+    }
+
+    #[test]
+    fn test_iter() {
+        let test = TestArray::new([2, 2], [1u8, 2, 3, 4]);
+        let values: Vec<u8> = test.as_array().iter().copied().collect();
+        assert_eq!(values, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_indexed_iter() {
+        let test = TestArray::new([2, 3], [1u8, 2, 3, 4, 5, 6]);
+        let values: Vec<([usize; 2], u8)> = test
+            .as_array()
+            .indexed_iter()
+            .map(|(index, &value)| (index, value))
+            .collect();
+        assert_eq!(
+            values,
+            [
+                ([0, 0], 1),
+                ([0, 1], 2),
+                ([0, 2], 3),
+                ([1, 0], 4),
+                ([1, 1], 5),
+                ([1, 2], 6)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_multi_dimension() {
+        let test = TestArray::new([2, 3], [1u8, 2, 3, 4, 5, 6]);
+        let array = test.as_array();
+        assert_eq!(array.get([0, 0]), Some(&1));
+        assert_eq!(array.get([0, 2]), Some(&3));
+        assert_eq!(array.get([1, 0]), Some(&4));
+        assert_eq!(array.get([1, 2]), Some(&6));
+        assert_eq!(array.get([2, 0]), None);
+        assert_eq!(array.get([0, 3]), None);
+    }
+
+    #[test]
+    fn test_get_mut_multi_dimension() {
+        let mut test = TestArray::new([2, 3], [1u8, 2, 3, 4, 5, 6]);
+        *test.as_array_mut().get_mut([1, 1]).unwrap() = 10;
+        assert!(test.as_array_mut().get_mut([0, 3]).is_none());
+        assert_eq!(test.as_array().data_as_slice(), &[1, 2, 3, 4, 10, 6]);
+    }
+
+    #[test]
+    fn test_iter_zero_sized_dimension() {
+        let test = TestArray::new([3, 0], [0u8]);
+        assert_eq!(test.as_array().iter().count(), 0);
+        assert_eq!(test.as_array().indexed_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut test = TestArray::new([4], [1i32, 2, 3, 4]);
+        for element in test.as_array_mut().iter_mut() {
+            *element *= 2;
+        }
+        assert_eq!(test.as_array().data_as_slice(), &[2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn test_as_cow_slice_borrows() {
+        let test = TestArray::new([3], [1i32, 2, 3]);
+        let data = test.as_array().as_cow_slice();
+        assert!(matches!(data, Cow::Borrowed(_)));
+        assert_eq!(&*data, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_to_vec_outlives_array() {
+        let data = {
+            let test = TestArray::new([2, 2], [1i32, 2, 3, 4]);
+            test.as_array().to_vec()
+        };
+        assert_eq!(data, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_set_value_unchecked() {
+        let mut test = TestArray::new([3], [1u16, 2, 3]);
+        unsafe { test.as_array_mut().set_value_unchecked(1, 20) };
+        assert_eq!(unsafe { test.as_array().get_value_unchecked(1) }, 20);
+        assert_eq!(test.as_array().data_as_slice(), &[1, 20, 3]);
+    }
+
+    #[test]
+    fn test_expect_shape() {
+        let test = TestArray::new([2, 3], [0u8; 6]);
+        let array = test.as_array();
+        assert_eq!(array.dimension_sizes(), [2, 3]);
+        assert!(array.expect_shape([2, 3]).is_ok());
+
+        let result = array.expect_shape([3, 2]);
+        assert!(matches!(
+            result,
+            Err(LVArrayError::DimensionMismatch { expected, actual })
+                if expected == [3, 2] && actual == [2, 3]
+        ));
+    }
+
+    #[test]
+    fn test_byte_size() {
+        let test = TestArray::new([2, 3], [0f64; 6]);
+        assert_eq!(test.as_array().byte_size(), 56);
+        assert_eq!(test.as_array().byte_size(), std::mem::size_of_val(&test));
+
+        // The data is aligned to 8 bytes after the single dimension.
+        let test = TestArray::new([3], [0f64; 3]);
+        assert_eq!(test.as_array().byte_size(), 32);
+
+        let test = TestArray::new([3], [0u8; 3]);
+        assert_eq!(test.as_array().byte_size(), 7);
+    }
+
+    #[test]
+    fn test_byte_size_corrupt_header() {
+        let test = TestArray::new([-1, 3], [0f64; 3]);
+        assert_eq!(test.as_array().byte_size(), 8);
+
+        let test = TestArray::new([i32::MAX, i32::MAX, i32::MAX], [0f64; 3]);
+        assert_eq!(test.as_array().byte_size(), usize::MAX);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut test = TestArray::new([2, 2], [1u8, 2, 3, 4]);
+        test.as_array_mut().clear();
+        assert_eq!(test.as_array().element_count(), 0);
+        assert_eq!(test.as_array().get_data_size(), 0);
+        assert!(test.as_array().data_as_slice().is_empty());
+        // The data is left in place to be reused.
+        assert_eq!(test.data, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_data_as_slice_capped() {
+        let test = TestArray::new([4], [1u8, 2, 3, 4]);
+        assert_eq!(test.as_array().data_as_slice_capped(2), &[1, 2]);
+        assert_eq!(test.as_array().data_as_slice_capped(10), &[1, 2, 3, 4]);
+
+        // The size claims more than the cap allows.
+        let test = TestArray::new([1000], [1u8, 2, 3, 4]);
+        assert_eq!(test.as_array().data_as_slice_capped(4), &[1, 2, 3, 4]);
+
+        // The dimensions overflow when multiplied.
+        let test = TestArray::new([65536, 65536], [1u8, 2, 3, 4]);
+        assert_eq!(test.as_array().data_as_slice_capped(4), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_safe_data_as_slice_clamps_to_allocation() {
+        // Claims 100 elements but only 3 are allocated.
+        let test = TestArray::new([100], [1.0f64, 2.0, 3.0]);
+        let allocated = std::mem::size_of_val(&test);
+        let array = test.as_array();
+        assert_eq!(array.element_count(), 100);
+        assert_eq!(array.safe_element_count(allocated), 3);
+        assert_eq!(array.safe_data_as_slice(allocated), &[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_safe_element_count_overflowing_header() {
+        let test = TestArray::new([65536, 65536], [1u8, 2, 3, 4]);
+        let allocated = std::mem::size_of_val(&test);
+        assert_eq!(test.as_array().safe_element_count(allocated), 4);
+
+        let test = TestArray::new([i32::MAX, i32::MAX, i32::MAX], [1u8, 2, 3, 4]);
+        let allocated = std::mem::size_of_val(&test);
+        assert_eq!(test.as_array().safe_data_as_slice(allocated), &[1, 2, 3, 4]);
+
+        let test = TestArray::new([-1, 3], [1u8, 2, 3, 4]);
+        let allocated = std::mem::size_of_val(&test);
+        assert_eq!(test.as_array().safe_element_count(allocated), 0);
+    }
+
+    #[test]
+    fn test_safe_data_as_slice_uses_dimensions_when_smaller() {
+        let test = TestArray::new([2], [1.0f64, 2.0, 3.0]);
+        let allocated = std::mem::size_of_val(&test);
+        assert_eq!(test.as_array().safe_data_as_slice(allocated), &[1.0, 2.0]);
+        assert!(test.as_array().safe_data_as_slice(4).is_empty());
+    }
+
+    #[test]
+    fn test_chunks_as() {
+        let test = TestArray::new([4], [1.0f64, 10.0, 2.0, 20.0]);
+        let pairs: Vec<[f64; 2]> = test.as_array().chunks_as::<2>().collect();
+        assert_eq!(pairs, [[1.0, 10.0], [2.0, 20.0]]);
+    }
+
+    #[test]
+    fn test_chunks_as_drops_partial_chunk() {
+        let test = TestArray::new([5], [1u8, 2, 3, 4, 5]);
+        let triples: Vec<[u8; 3]> = test.as_array().chunks_as::<3>().collect();
+        assert_eq!(triples, [[1, 2, 3]]);
+    }
+
+    #[test]
+    fn test_split_first_and_last() {
+        let test = TestArray::new([4], [1u8, 2, 3, 4]);
+        let array = test.as_array();
+        assert_eq!(array.split_first(), Some((&1, &[2, 3, 4][..])));
+        assert_eq!(array.split_last(), Some((&4, &[1, 2, 3][..])));
+
+        let empty = TestArray::new([0], [0u8]);
+        assert_eq!(empty.as_array().split_first(), None);
+        assert_eq!(empty.as_array().split_last(), None);
+    }
+
+    #[test]
+    fn test_as_2d() {
+        let test = TestArray::new([6], [1i32, 2, 3, 4, 5, 6]);
+        let rows = test.as_array().as_2d(3).unwrap();
+        assert_eq!(rows, [&[1, 2, 3], &[4, 5, 6]]);
+    }
+
+    #[test]
+    fn test_as_2d_invalid_columns() {
+        let test = TestArray::new([6], [1i32, 2, 3, 4, 5, 6]);
+        assert!(matches!(
+            test.as_array().as_2d(4),
+            Err(LVArrayError::InvalidColumnCount {
+                size: 6,
+                columns: 4
+            })
+        ));
+        assert!(test.as_array().as_2d(0).is_err());
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut test = TestArray::new([4], [1u8, 2, 3, 4]);
+        test.as_array_mut().swap(0, 3).unwrap();
+        assert_eq!(test.as_array().data_as_slice(), &[4, 2, 3, 1]);
+    }
+
+    #[test]
+    fn test_swap_out_of_range() {
+        let mut test = TestArray::new([4], [1u8, 2, 3, 4]);
+        let result = test.as_array_mut().swap(1, 4);
+        assert!(matches!(
+            result,
+            Err(LVArrayError::IndexOutOfRange { index: 4, size: 4 })
+        ));
+        assert_eq!(test.as_array().data_as_slice(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_get_two_mut() {
+        let mut test = TestArray::new([4], [1i32, 2, 3, 4]);
+        let (first, second) = test.as_array_mut().get_two_mut(3, 1).unwrap();
+        assert_eq!((*first, *second), (4, 2));
+        *first += 10;
+        *second += 20;
+        assert_eq!(test.as_array().data_as_slice(), &[1, 22, 3, 14]);
+    }
+
+    #[test]
+    fn test_get_two_mut_invalid_indexes() {
+        let mut test = TestArray::new([4], [1i32, 2, 3, 4]);
+        assert!(test.as_array_mut().get_two_mut(2, 2).is_none());
+        assert!(test.as_array_mut().get_two_mut(0, 4).is_none());
+    }
+
+    #[test]
+    fn test_fill_pattern() {
+        let mut test = TestArray::new([5], [0u8; 5]);
+        test.as_array_mut().fill_pattern(&[1, 2]);
+        assert_eq!(test.as_array().data_as_slice(), &[1, 2, 1, 2, 1]);
+
+        test.as_array_mut().fill_pattern(&[]);
+        assert_eq!(test.as_array().data_as_slice(), &[1, 2, 1, 2, 1]);
+
+        test.as_array_mut().fill_pattern(&[9, 8, 7, 6, 5, 4, 3]);
+        assert_eq!(test.as_array().data_as_slice(), &[9, 8, 7, 6, 5]);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut test = TestArray::new([5], [1u8, 2, 3, 4, 5]);
+        test.as_array_mut().rotate_left(2);
+        assert_eq!(test.as_array().data_as_slice(), &[3, 4, 5, 1, 2]);
+        test.as_array_mut().rotate_right(7);
+        assert_eq!(test.as_array().data_as_slice(), &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_total_from_shape() {
+        assert_eq!(total_from_shape(&[2, 3, 4]).unwrap(), 24);
+        assert_eq!(total_from_shape(&[]).unwrap(), 1);
+        assert_eq!(total_from_shape(&[5, 0]).unwrap(), 0);
+        assert!(matches!(
+            total_from_shape(&[usize::MAX, 2]),
+            Err(LVArrayError::SizeOverflow)
+        ));
+    }
+
+    #[test]
+    fn test_dims_from_shape() {
+        let dims: [i32; 2] = dims_from_shape(&[2, 3]).unwrap();
+        assert_eq!(dims, [2, 3]);
+
+        let result = dims_from_shape::<2>(&[2, 3, 4]);
+        assert!(matches!(
+            result,
+            Err(LVArrayError::DimensionCountMismatch {
+                expected: 2,
+                actual: 3
+            })
+        ));
+
+        let result = dims_from_shape::<2>(&[1 << 16, 1 << 16]);
+        assert!(matches!(result, Err(LVArrayError::SizeOverflow)));
+    }
+
+    #[test]
+    fn test_replace_non_finite() {
+        let mut test = TestArray::new(
+            [5],
+            [1.0f64, f64::NAN, f64::INFINITY, 2.0, f64::NEG_INFINITY],
+        );
+        let replaced = test.as_array_mut().replace_non_finite(0.0);
+        assert_eq!(replaced, 3);
+        assert_eq!(test.as_array().data_as_slice(), &[1.0, 0.0, 0.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn test_statistics() {
+        let test = TestArray::new([8], [2.0f64, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        let array = test.as_array();
+        assert_eq!(array.mean(), Some(5.0));
+        assert!((array.variance().unwrap() - 32.0 / 7.0).abs() < 1e-12);
+        assert!((array.std_dev().unwrap() - (32.0f64 / 7.0).sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_statistics_large_mean() {
+        let test = TestArray::new([4], [1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0]);
+        let array = test.as_array();
+        assert_eq!(array.mean(), Some(1e9 + 10.0));
+        assert!((array.variance().unwrap() - 30.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_statistics_too_few_elements() {
+        let test = TestArray::new([0], [0.0f64]);
+        assert_eq!(test.as_array().mean(), None);
+        assert_eq!(test.as_array().variance(), None);
+
+        let test = TestArray::new([1], [3.0f64]);
+        assert_eq!(test.as_array().mean(), Some(3.0));
+        assert_eq!(test.as_array().std_dev(), None);
+    }
+
+    #[test]
+    fn test_eq_trimmed() {
+        let test = TestArray::new([6], [b'a', b'b', b'c', 0, 0, 0]);
+        let array = test.as_array();
+        assert!(array.eq_trimmed(b"abc"));
+        assert!(array.eq_trimmed(b"abc\0"));
+        assert!(!array.eq_trimmed(b"ab"));
+        assert_ne!(array.data_as_slice(), b"abc");
+
+        let empty = TestArray::new([2], [0u8, 0]);
+        assert!(empty.as_array().eq_trimmed(&[]));
+    }
+
+    #[test]
+    fn test_find_subslice() {
+        let test = TestArray::new([8], *b"abcdefgh");
+        let array = test.as_array();
+        assert_eq!(array.find_subslice(b"abc"), Some(0));
+        assert_eq!(array.find_subslice(b"def"), Some(3));
+        assert_eq!(array.find_subslice(b"xyz"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "debug-util")]
+    fn test_hex_dump() {
+        let test = TestArray::new([18], *b"Hello, World!\n\x00\x01\xffA");
+        let expected = "\
+00000000: 4865 6c6c 6f2c 2057 6f72 6c64 210a 0001  Hello, World!...
+00000010: ff41                                     .A
+";
+        assert_eq!(test.as_array().hex_dump(), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "ndarray")]
+    fn test_copy_to_ndarray() {
+        let test = TestArray::new([2, 3], [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let mut output = ndarray::Array2::<f64>::zeros((2, 3));
+        test.as_array()
+            .copy_to_ndarray(&mut output.view_mut())
+            .unwrap();
+        assert_eq!(output, ndarray::arr2(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+    }
+
+    #[test]
+    #[cfg(feature = "ndarray")]
+    fn test_copy_to_ndarray_shape_mismatch() {
+        let test = TestArray::new([2, 3], [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let mut output = ndarray::Array2::<f64>::zeros((3, 2));
+        let result = test.as_array().copy_to_ndarray(&mut output.view_mut());
+        assert!(matches!(
+            result,
+            Err(LVArrayError::DimensionMismatch { .. })
+        ));
+        assert_eq!(output, ndarray::Array2::<f64>::zeros((3, 2)));
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn test_as_f16_slice() {
+        let mut test = TestArray::new([3], [0x3C00u16, 0xC000, 0x3800]);
+        let values: Vec<f32> = test
+            .as_array()
+            .as_f16_slice()
+            .iter()
+            .map(|value| value.to_f32())
+            .collect();
+        assert_eq!(values, [1.0, -2.0, 0.5]);
+
+        test.as_array_mut().as_f16_slice_mut()[0] = half::f16::from_f32(0.25);
+        assert_eq!(test.data[0], 0x3400);
+    }
+
+    #[test]
+    #[cfg(feature = "base64")]
+    fn test_to_base64() {
+        use base64::Engine;
+
+        let source = TestArray::new([6], *b"LV\x00\xFF\x10!");
+        let encoded = source.as_array().to_base64();
+        assert_eq!(encoded, "TFYA/xAh");
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(&encoded)
+            .unwrap();
+        assert_eq!(decoded, source.as_array().data_as_slice());
+
+        let empty = TestArray::new([0], [0u8]);
+        assert_eq!(empty.as_array().to_base64(), "");
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn test_to_bytes() {
+        let source = TestArray::new([4], [1u8, 2, 3, 4]);
+        let bytes = source.as_array().to_bytes();
+        assert_eq!(bytes, bytes::Bytes::from_static(&[1, 2, 3, 4]));
+
+        let mut destination = TestArray::new([4], [0u8; 4]);
+        destination
+            .as_array_mut()
+            .data_as_slice_mut()
+            .copy_from_slice(&bytes);
+        assert_eq!(destination.as_array().data_as_slice(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn test_data_as_bytes_round_trip() {
+        let mut test = TestArray::new([3], [1.0f64, -2.5, 3.25]);
+        let bytes = test.as_array().data_as_bytes();
+        assert_eq!(bytes.len(), 24);
+        assert_eq!(&bytes[8..16], &(-2.5f64).to_ne_bytes());
+
+        let values: &[f64] = bytemuck::cast_slice(bytes);
+        assert_eq!(values, &[1.0, -2.5, 3.25]);
+
+        test.as_array_mut().data_as_bytes_mut()[..8].copy_from_slice(&4.0f64.to_ne_bytes());
+        assert_eq!(test.as_array().data_as_slice(), &[4.0, -2.5, 3.25]);
+    }
+}
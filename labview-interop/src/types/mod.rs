@@ -2,6 +2,7 @@
 //! and functions for handling types from LabVIEW.
 
 pub mod array;
+pub mod strings;
 pub mod timestamp;
 
 use std::ffi::c_void;
@@ -10,6 +11,7 @@ use crate::memory::UHandle;
 
 //surface some of the common types.
 pub use array::{LVArray, LVArrayHandle};
+pub use strings::{WStr, WStrHandle};
 pub use timestamp::LVTime;
 
 /// Wrap a struct declaration to have the packing attributes
@@ -79,6 +81,103 @@ macro_rules! labview_layout {
     };
 }
 
+/// Declare a struct with [`labview_layout`] and generate accessors for each field.
+///
+/// For each field this generates a getter with the same name as the field and a
+/// setter with the name given after `=>`. These use [`std::ptr::read_unaligned`] and
+/// [`std::ptr::write_unaligned`] so they work with the packed 32 bit layout
+/// without taking a reference to the field.
+///
+/// The getters return a copy of the field so fields must not need to be dropped,
+/// which is checked at compile time. LabVIEW types such as handles are fine.
+/// Generic structs are not supported.
+///
+/// Field attributes are also applied to the accessors so fields can be
+/// conditional with `#[cfg(...)]`.
+///
+/// # Example
+/// ```
+/// use labview_interop::labview_cluster;
+///
+/// labview_cluster!(
+///     pub struct TestStruct {
+///         pub one: u8 => set_one,
+///         pub two: u16 => set_two,
+///         pub three: u32 => set_three,
+///     }
+/// );
+///
+/// let mut value = TestStruct {
+///     one: 1,
+///     two: 2,
+///     three: 3,
+/// };
+///
+/// value.set_three(30);
+/// assert_eq!(value.three(), 30);
+/// ```
+///
+/// Fields which need to be dropped are rejected at compile time.
+/// ```compile_fail
+/// labview_interop::labview_cluster!(
+///     pub struct Invalid {
+///         pub name: String => set_name,
+///     }
+/// );
+/// ```
+#[macro_export]
+macro_rules! labview_cluster {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident : $field_type:ty => $setter:ident
+            ),* $(,)?
+        }
+    ) => {
+        $crate::labview_layout!(
+            $(#[$meta])*
+            $vis struct $name {
+                $(
+                    $(#[$field_meta])*
+                    $field_vis $field: $field_type,
+                )*
+            }
+        );
+
+        $(
+            $(#[$field_meta])*
+            const _: () = assert!(
+                !::std::mem::needs_drop::<$field_type>(),
+                "labview_cluster fields must not need to be dropped"
+            );
+        )*
+
+        impl $name {
+            $(
+                #[doc = concat!("Get a copy of the `", stringify!($field), "` field.")]
+                $(#[$field_meta])*
+                #[allow(dead_code)]
+                $field_vis fn $field(&self) -> $field_type {
+                    // Safety: The pointer is created from a valid reference.
+                    unsafe { ::std::ptr::read_unaligned(::std::ptr::addr_of!(self.$field)) }
+                }
+
+                #[doc = concat!("Set the `", stringify!($field), "` field.")]
+                $(#[$field_meta])*
+                #[allow(dead_code)]
+                $field_vis fn $setter(&mut self, value: $field_type) {
+                    // Safety: The pointer is created from a valid reference.
+                    unsafe {
+                        ::std::ptr::write_unaligned(::std::ptr::addr_of_mut!(self.$field), value)
+                    }
+                }
+            )*
+        }
+    };
+}
+
 /// Represents a LabVIEW Variant. The internal structure is undefined
 /// by NI and therefore unavailable.
 ///
@@ -117,3 +216,109 @@ labview_layout!(
         _pad2: u32,
     }
 );
+
+/// Accessors which are safe to use on the packed 32 bit layout.
+///
+/// On 32 bit targets you cannot take a reference to the fields so
+/// these copy the values out with [`std::ptr::read_unaligned`].
+impl<T> Waveform<T> {
+    /// The timestamp for the first data value.
+    pub fn t0(&self) -> LVTime {
+        // Safety: The pointer is created from a valid reference.
+        unsafe { std::ptr::read_unaligned(std::ptr::addr_of!(self.t0)) }
+    }
+
+    /// The time in seconds between samples.
+    pub fn dt(&self) -> f64 {
+        // Safety: The pointer is created from a valid reference.
+        unsafe { std::ptr::read_unaligned(std::ptr::addr_of!(self.dt)) }
+    }
+
+    /// A copy of the handle to the 1D data array.
+    pub fn data_handle(&self) -> LVArrayHandle<1, T> {
+        // Safety: The pointer is created from a valid reference.
+        unsafe { std::ptr::read_unaligned(std::ptr::addr_of!(self.data)) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::labview_cluster!(
+        struct TestCluster {
+            one: u8 => set_one,
+            two: f64 => set_two,
+            three: u16 => set_three,
+        }
+    );
+
+    crate::labview_cluster!(
+        struct ConditionalCluster {
+            one: u8 => set_one,
+            #[cfg(target_pointer_width = "32")]
+            _pad: u32 => set_pad,
+            #[cfg(target_pointer_width = "64")]
+            _pad64: u64 => set_pad64,
+            two: f64 => set_two,
+        }
+    );
+
+    #[test]
+    fn test_cluster_accessors() {
+        let mut cluster = TestCluster {
+            one: 1,
+            two: 2.5,
+            three: 3,
+        };
+
+        assert_eq!(cluster.one(), 1);
+        assert_eq!(cluster.two(), 2.5);
+        assert_eq!(cluster.three(), 3);
+
+        cluster.set_one(10);
+        cluster.set_two(-1.25);
+        cluster.set_three(300);
+        assert_eq!(cluster.one(), 10);
+        assert_eq!(cluster.two(), -1.25);
+        assert_eq!(cluster.three(), 300);
+    }
+
+    #[test]
+    fn test_cluster_conditional_fields() {
+        let mut cluster = ConditionalCluster {
+            one: 1,
+            #[cfg(target_pointer_width = "32")]
+            _pad: 0,
+            #[cfg(target_pointer_width = "64")]
+            _pad64: 0,
+            two: 2.5,
+        };
+
+        cluster.set_two(5.0);
+        assert_eq!(cluster.one(), 1);
+        assert_eq!(cluster.two(), 5.0);
+    }
+
+    #[test]
+    fn test_waveform_accessors() {
+        let mut data = std::ptr::null_mut();
+        let waveform = Waveform::<f64> {
+            t0: LVTime::from_parts(3758974472, 0x8000_0000_0000_0000),
+            dt: 0.25,
+            data: UHandle(&mut data),
+            _pad: 0,
+            #[cfg(target_pointer_width = "32")]
+            _mini_pad: 0,
+            attributes: LVVariant(UHandle(std::ptr::null_mut())),
+            _pad2: 0,
+        };
+
+        assert_eq!(
+            waveform.t0(),
+            LVTime::from_parts(3758974472, 0x8000_0000_0000_0000)
+        );
+        assert_eq!(waveform.dt(), 0.25);
+        assert_eq!(waveform.data_handle().0, &mut data as *mut _);
+    }
+}
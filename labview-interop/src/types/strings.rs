@@ -1,8 +1,141 @@
 //! Handle the various string times that the LabVIEW
 //! interface provides.
 //!
-//!
+//! Currently this supports the UTF-16 wide string with [`WStr`].
+
+use encoding_rs::UTF_16LE;
+
+use crate::labview_layout;
+use crate::memory::UHandle;
+
+labview_layout!(
+    /// Internal LabVIEW wide string representation.
+    ///
+    /// This is the length in UTF-16 code units followed by the code units.
+    /// The code units are stored in the native byte order of the platform,
+    /// which is little endian on all platforms LabVIEW currently supports.
+    pub struct WStr {
+        size: i32,
+        data: u16,
+    }
+);
+
+/// A handle to a LabVIEW wide string.
+pub type WStrHandle = UHandle<WStr>;
+
+impl WStr {
+    /// Get the length of the string in UTF-16 code units.
+    ///
+    /// A negative length from a corrupt header is treated as empty.
+    /// Works with the packed structures found in the 32 bit interface.
+    pub fn size(&self) -> usize {
+        let size_ptr = std::ptr::addr_of!(self.size);
+        // Safety: The pointer is created from a valid reference.
+        let size = unsafe { std::ptr::read_unaligned(size_ptr) };
+        size.max(0) as usize
+    }
+
+    /// Get the code units as a slice.
+    ///
+    /// The data follows the 4 byte length so is aligned for `u16` on both
+    /// 32 and 64 bit targets.
+    pub fn as_slice(&self) -> &[u16] {
+        let data_ptr = std::ptr::addr_of!(self.data);
+        // Safety: The size is set by LabVIEW to be valid.
+        unsafe { std::slice::from_raw_parts(data_ptr, self.size()) }
+    }
+
+    /// Get the code units as a mutable slice.
+    ///
+    /// This can change the contents but not the length of the string.
+    pub fn as_mut_slice(&mut self) -> &mut [u16] {
+        let size = self.size();
+        let data_ptr = std::ptr::addr_of_mut!(self.data);
+        // Safety: The size is set by LabVIEW to be valid.
+        unsafe { std::slice::from_raw_parts_mut(data_ptr, size) }
+    }
+
+    /// Decode the string into a Rust [`String`].
+    ///
+    /// The code units are converted to little endian bytes before decoding as
+    /// UTF-16LE so this is correct whatever the byte order of the platform.
+    /// Invalid UTF-16 such as unpaired surrogates is replaced with U+FFFD.
+    pub fn to_rust_string(&self) -> String {
+        let bytes: Vec<u8> = self
+            .as_slice()
+            .iter()
+            .flat_map(|unit| unit.to_le_bytes())
+            .collect();
+        let (decoded, _had_errors) = UTF_16LE.decode_without_bom_handling(&bytes);
+        decoded.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Matches the layout of [`WStr`] with the data inline so
+    /// we can build strings for testing without LabVIEW.
+    #[repr(C)]
+    struct TestWStr<const N: usize> {
+        size: i32,
+        data: [u16; N],
+    }
+
+    impl<const N: usize> TestWStr<N> {
+        fn new(data: [u16; N]) -> Self {
+            Self {
+                size: N as i32,
+                data,
+            }
+        }
+
+        fn as_wstr(&self) -> &WStr {
+            // Safety: The layout matches WStr and the data is inline.
+            unsafe { &*(self as *const Self as *const WStr) }
+        }
+
+        fn as_wstr_mut(&mut self) -> &mut WStr {
+            // Safety: The layout matches WStr and the data is inline.
+            unsafe { &mut *(self as *mut Self as *mut WStr) }
+        }
+    }
+
+    #[test]
+    fn test_wstr_to_rust_string() {
+        // "hé€😀" including a surrogate pair.
+        let test = TestWStr::new([0x68, 0xE9, 0x20AC, 0xD83D, 0xDE00]);
+        let wstr = test.as_wstr();
+        assert_eq!(wstr.size(), 5);
+        assert_eq!(wstr.as_slice(), &[0x68, 0xE9, 0x20AC, 0xD83D, 0xDE00]);
+        assert_eq!(wstr.to_rust_string(), "hé€😀");
+    }
+
+    #[test]
+    fn test_wstr_unpaired_surrogate() {
+        let test = TestWStr::new([0x61, 0xD83D, 0x62]);
+        assert_eq!(test.as_wstr().to_rust_string(), "a\u{FFFD}b");
+    }
+
+    #[test]
+    fn test_wstr_empty() {
+        let mut empty = TestWStr {
+            size: 0,
+            data: [0x61u16],
+        };
+        assert!(empty.as_wstr().as_slice().is_empty());
+        assert_eq!(empty.as_wstr().to_rust_string(), "");
+
+        empty.size = -1;
+        assert_eq!(empty.as_wstr().size(), 0);
+        assert!(empty.as_wstr_mut().as_mut_slice().is_empty());
+    }
 
-// Placeholder for now.
-// Priority would be to support the LVString type as
-// that is what we might find in a cluster.
+    #[test]
+    fn test_wstr_as_mut_slice() {
+        let mut test = TestWStr::new([0x61, 0x62]);
+        test.as_wstr_mut().as_mut_slice()[1] = 0x63;
+        assert_eq!(test.as_wstr().to_rust_string(), "ac");
+    }
+}
@@ -1,15 +1,29 @@
 //! Support for exchanging passwords with LabVIEWs timestamp format.
 //!
 //! This includes binary formats, to and from 1904 epoch, unix (1970) epoch
-//! and optionally chrono DateTime with the `chrono` feature.
+//! and optionally chrono DateTime with the `chrono` feature or time
+//! OffsetDateTime with the `time` feature.
 //!
 
+use std::ops::{Add, Sub};
+use std::time::{Duration, SystemTime};
 use thiserror::Error;
 
+/// Errors converting times.
+///
+/// Some variants only exist with the `chrono` or `time` features so this is
+/// non-exhaustive to keep the features additive.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum LVTimeError {
     #[error("Cannot generate a chrono time as it is out of range.")]
     ChronoOutOfRange,
+    #[cfg(feature = "chrono")]
+    #[error("Cannot parse the time string.")]
+    Parse(#[from] ::chrono::ParseError),
+    #[cfg(feature = "time")]
+    #[error("Cannot generate a time OffsetDateTime as it is out of range.")]
+    TimeOutOfRange,
 }
 
 /// Mirrors the internal LabVIEW timestamp structure so
@@ -21,6 +35,11 @@ pub struct LVTime(u128);
 ///The Unix Epoch in LabVIEW epoch seconds for shifting timestamps between them.
 pub const UNIX_EPOCH_IN_LV_SECONDS: f64 = 2082844800.0;
 
+///The Unix Epoch in LabVIEW epoch seconds as an integer for exact conversions.
+pub const UNIX_EPOCH_IN_LV_SECONDS_I64: i64 = 2082844800;
+
+const NANOSECONDS_PER_SECOND: i64 = 1_000_000_000;
+
 //todo:
 // * from/to bytes
 
@@ -31,6 +50,33 @@ impl LVTime {
         (fractional as f64) / 0xFFFF_FFFF_FFFF_FFFFu64 as f64
     }
 
+    /// Format the sub-second component as a string of `digits` decimal places.
+    ///
+    /// This is just the digits after the decimal point so `0.5` seconds to 3 digits
+    /// is `"500"`, and 0 digits is an empty string. The value is rounded to the
+    /// nearest digit but never rounds up into the next whole second, so `0.9999`
+    /// to 3 digits is `"999"`.
+    ///
+    /// The fraction has a resolution of around 5e-20 seconds so any digits after
+    /// the 19th are always zero.
+    pub fn fractional_string(&self, digits: usize) -> String {
+        const MAX_DIGITS: usize = 19;
+        let significant_digits = digits.min(MAX_DIGITS);
+        let fractions = self.to_parts().1 as u128;
+        let scale = 10u128.pow(significant_digits as u32);
+        // Round to nearest by adding half of the divisor before shifting.
+        let rounded = ((fractions * scale) + (1 << 63)) >> 64;
+        let value = rounded.min(scale - 1);
+
+        let mut output = if significant_digits == 0 {
+            String::new()
+        } else {
+            format!("{value:0significant_digits$}")
+        };
+        output.extend(std::iter::repeat_n('0', digits - significant_digits));
+        output
+    }
+
     ///Extract the seconds component which is referenced to the LabVIEW epoc.
     pub fn seconds(&self) -> u64 {
         (self.0 >> 64) as u64
@@ -62,6 +108,47 @@ impl LVTime {
         Self::from_lv_epoch(lv_epoch)
     }
 
+    /// The sub-second component as whole nanoseconds, rounded down.
+    fn subsec_nanos(&self) -> u32 {
+        let fractions = self.to_parts().1 as u128;
+        ((fractions * NANOSECONDS_PER_SECOND as u128) >> 64) as u32
+    }
+
+    /// The fractional part for a number of nanoseconds.
+    ///
+    /// This rounds up so converting back with [`LVTime::subsec_nanos`] gives the same value.
+    fn fractions_from_nanos(nanoseconds: u32) -> u64 {
+        ((nanoseconds as u128) << 64).div_ceil(NANOSECONDS_PER_SECOND as u128) as u64
+    }
+
+    /// The current system time.
+    ///
+    /// This has the same precision as converting from a [`SystemTime`].
+    pub fn now() -> Self {
+        SystemTime::now().into()
+    }
+
+    /// To the number of nanoseconds since the unix epoch.
+    ///
+    /// This is calculated from the integer parts so it keeps more precision
+    /// than [`LVTime::to_unix_epoch`]. Times outside the range of an `i64`
+    /// (around 292 years either side of 1970) saturate to `i64::MIN` or `i64::MAX`.
+    pub fn to_unix_nanos(&self) -> i64 {
+        // LabVIEW stores the seconds as a signed value.
+        let unix_seconds = self.seconds() as i64 as i128 - UNIX_EPOCH_IN_LV_SECONDS_I64 as i128;
+        let nanoseconds = self.subsec_nanos() as i128;
+        let total = unix_seconds * NANOSECONDS_PER_SECOND as i128 + nanoseconds;
+        total.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
+    /// From the number of nanoseconds since the unix epoch.
+    pub fn from_unix_nanos(nanoseconds: i64) -> Self {
+        let unix_seconds = nanoseconds.div_euclid(NANOSECONDS_PER_SECOND);
+        let sub_nanoseconds = nanoseconds.rem_euclid(NANOSECONDS_PER_SECOND) as u32;
+        let seconds = unix_seconds + UNIX_EPOCH_IN_LV_SECONDS_I64;
+        Self::from_parts(seconds as u64, Self::fractions_from_nanos(sub_nanoseconds))
+    }
+
     /// Build from the full seconds and fractional second parts.
     pub fn from_parts(seconds: u64, fractions: u64) -> Self {
         let time = (seconds as u128) << 64 | (fractions as u128);
@@ -95,18 +182,137 @@ impl LVTime {
     }
 }
 
+/// This is calculated from the whole seconds and nanoseconds so keeps full
+/// nanosecond precision. Times before 1970 and 1904 use the signed seconds in
+/// the same way as [`LVTime::from_unix_nanos`].
+///
+/// # Panics
+///
+/// Panics if the time is outside the range of the timestamp, which is around
+/// 292 billion years either side of 1904.
+impl From<SystemTime> for LVTime {
+    fn from(value: SystemTime) -> Self {
+        let unix_epoch = LVTime::from_parts(UNIX_EPOCH_IN_LV_SECONDS_I64 as u64, 0);
+        match value.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(since_epoch) => unix_epoch + since_epoch,
+            Err(error) => unix_epoch - error.duration(),
+        }
+    }
+}
+
+impl LVTime {
+    /// Convert a duration to the same fixed point format as the timestamp.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the duration is too long to represent, which is 2^63 seconds or more.
+    fn duration_to_raw(duration: Duration) -> i128 {
+        let fractions = Self::fractions_from_nanos(duration.subsec_nanos());
+        let raw = ((duration.as_secs() as u128) << 64) | fractions as u128;
+        i128::try_from(raw).expect("duration is too long for a timestamp")
+    }
+}
+
+/// The arithmetic uses the signed seconds used by LabVIEW so times before the
+/// 1904 epoch work as expected.
+///
+/// # Panics
+///
+/// Panics if the result is outside the range of the timestamp.
+impl Add<Duration> for LVTime {
+    type Output = LVTime;
+
+    fn add(self, rhs: Duration) -> Self::Output {
+        let raw = (self.0 as i128)
+            .checked_add(Self::duration_to_raw(rhs))
+            .expect("overflow when adding duration to timestamp");
+        LVTime(raw as u128)
+    }
+}
+
+/// # Panics
+///
+/// Panics if the result is outside the range of the timestamp.
+impl Sub<Duration> for LVTime {
+    type Output = LVTime;
+
+    fn sub(self, rhs: Duration) -> Self::Output {
+        let raw = (self.0 as i128)
+            .checked_sub(Self::duration_to_raw(rhs))
+            .expect("overflow when subtracting duration from timestamp");
+        LVTime(raw as u128)
+    }
+}
+
+/// Get the duration between two timestamps.
+///
+/// A [`Duration`] can't be negative so this saturates to [`Duration::ZERO`]
+/// if `rhs` is later than `self`, matching [`std::time::Instant`].
+/// The sub-second part is rounded down to whole nanoseconds.
+impl Sub<LVTime> for LVTime {
+    type Output = Duration;
+
+    fn sub(self, rhs: LVTime) -> Self::Output {
+        let difference = (self.0 as i128).saturating_sub(rhs.0 as i128);
+        if difference <= 0 {
+            return Duration::ZERO;
+        }
+        let difference = LVTime(difference as u128);
+        Duration::new(difference.seconds(), difference.subsec_nanos())
+    }
+}
+
 #[cfg(feature = "chrono")]
 mod chrono {
 
     use super::*;
-    use ::chrono::{DateTime, NaiveDateTime, Utc};
+    use ::chrono::{DateTime, Datelike, NaiveDateTime, NaiveTime, Utc, Weekday};
+
+    impl LVTime {
+        /// Parse an RFC 3339 time string such as `2023-02-11T15:34:32.5+00:00`.
+        pub fn from_rfc3339(time: &str) -> Result<Self, LVTimeError> {
+            let date_time = DateTime::parse_from_rfc3339(time)?;
+            Ok(date_time.with_timezone(&Utc).into())
+        }
+
+        /// Truncate the time to midnight UTC on the same day.
+        ///
+        /// This is useful for binning data by day.
+        pub fn start_of_day_utc(&self) -> Result<Self, LVTimeError> {
+            let date_time: DateTime<Utc> = (*self).try_into()?;
+            let midnight = date_time.date_naive().and_time(NaiveTime::MIN).and_utc();
+            Ok(midnight.into())
+        }
+
+        /// Get the day of the week in UTC.
+        pub fn weekday(&self) -> Result<Weekday, LVTimeError> {
+            let date_time: DateTime<Utc> = (*self).try_into()?;
+            Ok(date_time.weekday())
+        }
+
+        /// Get the day of the year in UTC, starting from 1 for the 1st of January.
+        pub fn ordinal_day(&self) -> Result<u32, LVTimeError> {
+            let date_time: DateTime<Utc> = (*self).try_into()?;
+            Ok(date_time.ordinal())
+        }
+    }
 
     impl TryFrom<LVTime> for DateTime<Utc> {
         type Error = LVTimeError;
 
         fn try_from(value: LVTime) -> Result<Self, Self::Error> {
             let naive_time: NaiveDateTime = value.try_into()?;
-            Ok(DateTime::<Utc>::from_utc(naive_time, Utc))
+            Ok(DateTime::<Utc>::from_naive_utc_and_offset(naive_time, Utc))
+        }
+    }
+
+    /// Times before the LabVIEW epoch wrap the seconds in the same way as
+    /// the signed seconds used by LabVIEW.
+    impl From<DateTime<Utc>> for LVTime {
+        fn from(value: DateTime<Utc>) -> Self {
+            let seconds = value.timestamp() + UNIX_EPOCH_IN_LV_SECONDS_I64;
+            let fractions = LVTime::fractions_from_nanos(value.timestamp_subsec_nanos());
+            LVTime::from_parts(seconds as u64, fractions)
         }
     }
 
@@ -114,14 +320,46 @@ mod chrono {
         type Error = LVTimeError;
 
         fn try_from(value: LVTime) -> Result<Self, Self::Error> {
-            let seconds_for_time: i64 = value.seconds() as i64 - UNIX_EPOCH_IN_LV_SECONDS as i64;
-            let nanoseconds = value.sub_seconds() * 1_000_000_000f64;
-            Self::from_timestamp_opt(seconds_for_time, nanoseconds as u32)
+            let seconds_for_time: i64 = value.seconds() as i64 - UNIX_EPOCH_IN_LV_SECONDS_I64;
+            DateTime::from_timestamp(seconds_for_time, value.subsec_nanos())
+                .map(|date_time| date_time.naive_utc())
                 .ok_or(LVTimeError::ChronoOutOfRange)
         }
     }
 }
 
+#[cfg(feature = "time")]
+mod time {
+
+    use super::*;
+    use ::time::OffsetDateTime;
+
+    impl TryFrom<&LVTime> for OffsetDateTime {
+        type Error = LVTimeError;
+
+        fn try_from(value: &LVTime) -> Result<Self, Self::Error> {
+            // LabVIEW stores the seconds as a signed value.
+            let seconds = value.seconds() as i64 as i128 - UNIX_EPOCH_IN_LV_SECONDS_I64 as i128;
+            let nanoseconds =
+                seconds * NANOSECONDS_PER_SECOND as i128 + value.subsec_nanos() as i128;
+            OffsetDateTime::from_unix_timestamp_nanos(nanoseconds)
+                .map_err(|_| LVTimeError::TimeOutOfRange)
+        }
+    }
+
+    /// Times before the LabVIEW epoch wrap the seconds in the same way as
+    /// the signed seconds used by LabVIEW.
+    impl From<OffsetDateTime> for LVTime {
+        fn from(value: OffsetDateTime) -> Self {
+            let seconds = value.unix_timestamp() + UNIX_EPOCH_IN_LV_SECONDS_I64;
+            LVTime::from_parts(
+                seconds as u64,
+                LVTime::fractions_from_nanos(value.nanosecond()),
+            )
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,6 +384,127 @@ mod tests {
         assert_eq!(time, LVTime::from_unix_epoch(1676129672.5f64));
     }
 
+    #[test]
+    fn test_fractional_string() {
+        let time = LVTime::from_parts(20, 0x8000_0000_0000_0000);
+        assert_eq!(time.fractional_string(3), "500");
+        assert_eq!(time.fractional_string(9), "500000000");
+        assert_eq!(time.fractional_string(0), "");
+        assert_eq!(time.fractional_string(21), "500000000000000000000");
+    }
+
+    #[test]
+    fn test_fractional_string_rounding() {
+        let time = LVTime::from_lv_epoch(20.1236);
+        assert_eq!(time.fractional_string(3), "124");
+        assert_eq!(time.fractional_string(1), "1");
+
+        let time = LVTime::from_parts(20, u64::MAX);
+        assert_eq!(time.fractional_string(3), "999");
+        assert_eq!(time.fractional_string(0), "");
+    }
+
+    #[test]
+    fn test_to_from_unix_nanos() {
+        let time = LVTime::from_parts(3758974472, 0x8000_0000_0000_0000);
+        assert_eq!(time.to_unix_nanos(), 1_676_129_672_500_000_000);
+        assert_eq!(LVTime::from_unix_nanos(1_676_129_672_500_000_000), time);
+
+        let nanos = 1_676_129_672_123_456_789;
+        assert_eq!(LVTime::from_unix_nanos(nanos).to_unix_nanos(), nanos);
+    }
+
+    #[test]
+    fn test_to_from_unix_nanos_before_epoch() {
+        let time = LVTime::from_unix_nanos(-1_500_000_000);
+        assert_eq!(time.to_parts(), (2082844798, 0x8000_0000_0000_0000));
+        assert_eq!(time.to_unix_nanos(), -1_500_000_000);
+    }
+
+    #[test]
+    fn test_to_unix_nanos_saturates() {
+        let time = LVTime::from_parts(u64::MAX >> 1, 0);
+        assert_eq!(time.to_unix_nanos(), i64::MAX);
+    }
+
+    #[test]
+    fn test_add_sub_duration() {
+        let time = LVTime::from_parts(20, 0x8000_0000_0000_0000);
+        let later = time + Duration::from_millis(1750);
+        assert_eq!(later.to_parts(), (22, 0x4000_0000_0000_0000));
+        assert_eq!(later - Duration::from_millis(1750), time);
+    }
+
+    #[test]
+    fn test_sub_duration_before_lv_epoch() {
+        let time = LVTime::from_parts(1, 0);
+        let earlier = time - Duration::from_millis(1500);
+        assert_eq!(earlier.to_parts(), (-1i64 as u64, 0x8000_0000_0000_0000));
+        assert_eq!(earlier.to_unix_nanos(), -2_082_844_800_500_000_000);
+        assert_eq!(earlier + Duration::from_millis(1500), time);
+    }
+
+    #[test]
+    fn test_sub_times() {
+        let start = LVTime::from_parts(20, 0xC000_0000_0000_0000);
+        let end = LVTime::from_parts(22, 0x4000_0000_0000_0000);
+        assert_eq!(end - start, Duration::from_millis(1500));
+        assert_eq!(start - end, Duration::ZERO);
+
+        let nanos = Duration::new(5, 123_456_789);
+        assert_eq!((start + nanos) - start, nanos);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_duration_overflow() {
+        let _ = LVTime::from_parts(i64::MAX as u64, 0) + Duration::from_secs(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_duration_max() {
+        let _ = LVTime::from_parts(20, 0) + Duration::MAX;
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sub_duration_max() {
+        let _ = LVTime::from_parts(20, 0) - Duration::MAX;
+    }
+
+    #[test]
+    fn test_from_system_time() {
+        let system_time = SystemTime::UNIX_EPOCH + Duration::from_millis(1_676_129_672_500);
+        let time: LVTime = system_time.into();
+        assert_eq!(time, LVTime::from_parts(3758974472, 0x8000_0000_0000_0000));
+
+        let before_epoch = SystemTime::UNIX_EPOCH - Duration::from_secs(10);
+        let time: LVTime = before_epoch.into();
+        assert_eq!(time.to_parts(), (2082844790, 0));
+
+        let nanos = SystemTime::UNIX_EPOCH + Duration::new(1_676_129_672, 123_456_789);
+        let time: LVTime = nanos.into();
+        assert_eq!(time.to_unix_nanos(), 1_676_129_672_123_456_789);
+    }
+
+    #[test]
+    fn test_from_system_time_before_lv_epoch() {
+        // 1903-12-31T23:59:58.5Z, one and a half seconds before the LabVIEW epoch.
+        let system_time = SystemTime::UNIX_EPOCH - Duration::from_millis(2_082_844_801_500);
+        let time: LVTime = system_time.into();
+        assert_eq!(time.to_parts(), (-2i64 as u64, 0x8000_0000_0000_0000));
+        assert_eq!(time.to_unix_nanos(), -2_082_844_801_500_000_000);
+    }
+
+    #[test]
+    fn test_now() {
+        let before = LVTime::from(SystemTime::now());
+        let now = LVTime::now();
+        assert!(now.to_unix_epoch() >= before.to_unix_epoch());
+        assert!(now.to_unix_epoch() > 1_676_129_672.0);
+    }
+
     #[test]
     fn test_to_from_le_bytes() {
         let time = LVTime::from_parts(20, 0x8000_0000_0000_0000);
@@ -175,11 +534,12 @@ mod tests {
 #[cfg(feature = "chrono")]
 mod chrono_tests {
 
-    use super::{LVTime, UNIX_EPOCH_IN_LV_SECONDS};
+    use super::{LVTime, LVTimeError, UNIX_EPOCH_IN_LV_SECONDS};
     use chrono::NaiveDateTime;
-    use chrono::{DateTime, Utc};
+    use chrono::{DateTime, Utc, Weekday};
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn datetime_from_lv_time() {
         let date_time: DateTime<Utc> = LVTime::from_lv_epoch(3758974472.02440977f64)
             .try_into()
@@ -187,13 +547,115 @@ mod chrono_tests {
         let naive: NaiveDateTime = LVTime::from_lv_epoch(3758974472.02440977f64)
             .try_into()
             .unwrap();
-        let expected_naive = NaiveDateTime::from_timestamp_opt(
-            3758974472 - UNIX_EPOCH_IN_LV_SECONDS as i64,
-            024409770,
-        )
-        .unwrap();
-        let expected = DateTime::<Utc>::from_utc(expected_naive, Utc);
+        let expected =
+            DateTime::from_timestamp(3758974472 - UNIX_EPOCH_IN_LV_SECONDS as i64, 24_409_770)
+                .unwrap();
+        let expected_naive = expected.naive_utc();
         assert_eq!(date_time, expected);
         assert_eq!(naive, expected_naive)
     }
+
+    #[test]
+    fn datetime_round_trip_nanoseconds() {
+        for nanoseconds in [1, 7, 123_456_789, 999_999_999] {
+            let date_time = DateTime::from_timestamp(1676129672, nanoseconds).unwrap();
+            let time: LVTime = date_time.into();
+            assert_eq!(DateTime::<Utc>::try_from(time).unwrap(), date_time);
+            assert_eq!(
+                time.to_unix_nanos(),
+                1_676_129_672_000_000_000 + nanoseconds as i64
+            );
+        }
+
+        let time = LVTime::from_rfc3339("2023-02-11T15:34:32.123456789Z").unwrap();
+        assert_eq!(time.to_unix_nanos(), 1_676_129_672_123_456_789);
+    }
+
+    #[test]
+    fn lv_time_from_rfc3339() {
+        let time = LVTime::from_rfc3339("2023-02-11T15:34:32.5+00:00").unwrap();
+        assert_eq!(time, LVTime::from_parts(3758974472, 0x8000_0000_0000_0000));
+
+        let offset_time = LVTime::from_rfc3339("2023-02-11T16:34:32.5+01:00").unwrap();
+        assert_eq!(time, offset_time);
+    }
+
+    #[test]
+    fn lv_time_start_of_day() {
+        let time = LVTime::from_rfc3339("2023-02-11T15:34:32.5Z").unwrap();
+        let expected = LVTime::from_rfc3339("2023-02-11T00:00:00Z").unwrap();
+        assert_eq!(time.start_of_day_utc().unwrap(), expected);
+        assert_eq!(expected.start_of_day_utc().unwrap(), expected);
+
+        let out_of_range = LVTime::from_parts(i64::MAX as u64, 0);
+        assert!(matches!(
+            out_of_range.start_of_day_utc(),
+            Err(LVTimeError::ChronoOutOfRange)
+        ));
+    }
+
+    #[test]
+    fn lv_time_calendar_days() {
+        let time = LVTime::from_rfc3339("2023-02-11T15:34:32.5Z").unwrap();
+        assert_eq!(time.weekday().unwrap(), Weekday::Sat);
+        assert_eq!(time.ordinal_day().unwrap(), 42);
+
+        let out_of_range = LVTime::from_parts(i64::MAX as u64, 0);
+        assert!(out_of_range.weekday().is_err());
+    }
+
+    #[test]
+    fn lv_time_from_invalid_rfc3339() {
+        let result = LVTime::from_rfc3339("11/02/2023 15:34");
+        assert!(matches!(result, Err(LVTimeError::Parse(_))));
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "time")]
+mod time_tests {
+
+    use super::{LVTime, LVTimeError};
+    use time::{Duration, OffsetDateTime, UtcOffset};
+
+    #[test]
+    fn offset_date_time_from_lv_time() {
+        let time = LVTime::from_parts(3758974472, 0x8000_0000_0000_0000);
+        let date_time = OffsetDateTime::try_from(&time).unwrap();
+        let expected =
+            OffsetDateTime::from_unix_timestamp(1676129672).unwrap() + Duration::milliseconds(500);
+        assert_eq!(date_time, expected);
+    }
+
+    #[test]
+    fn lv_time_from_offset_date_time() {
+        let date_time =
+            OffsetDateTime::from_unix_timestamp(1676129672).unwrap() + Duration::milliseconds(500);
+        let time: LVTime = date_time.into();
+        assert_eq!(time, LVTime::from_parts(3758974472, 0x8000_0000_0000_0000));
+
+        let offset = date_time.to_offset(UtcOffset::from_hms(1, 0, 0).unwrap());
+        assert_eq!(LVTime::from(offset), time);
+    }
+
+    #[test]
+    fn offset_date_time_round_trip() {
+        let date_time =
+            OffsetDateTime::from_unix_timestamp_nanos(1_676_129_672_123_456_789).unwrap();
+        let time: LVTime = date_time.into();
+        assert_eq!(OffsetDateTime::try_from(&time).unwrap(), date_time);
+
+        let before_epoch = OffsetDateTime::from_unix_timestamp_nanos(-1_500_000_000).unwrap();
+        let time: LVTime = before_epoch.into();
+        assert_eq!(OffsetDateTime::try_from(&time).unwrap(), before_epoch);
+    }
+
+    #[test]
+    fn offset_date_time_out_of_range() {
+        let time = LVTime::from_parts(i64::MAX as u64, 0);
+        assert!(matches!(
+            OffsetDateTime::try_from(&time),
+            Err(LVTimeError::TimeOutOfRange)
+        ));
+    }
 }
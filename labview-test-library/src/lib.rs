@@ -1,19 +1,25 @@
-use labview_interop::labview_layout;
-use labview_interop::types::{array, LVArray, LVArrayHandle, LVTime, LVVariant, Waveform};
-use std::ptr::{addr_of, read_unaligned};
+//! Test functions called from the LabVIEW test project.
+//!
+//! These are exported for LabVIEW so take raw pointers from the caller.
+
+use labview_interop::types::{LVArrayHandle, LVTime, LVVariant, Waveform};
+use labview_interop::{labview_cluster, labview_layout};
 
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn timestamp_to_epoch(timestamp: *const LVTime) -> f64 {
     unsafe { (*timestamp).to_lv_epoch() }
 }
 
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn timestamp_from_epoch(seconds_since_epoch: f64, timestamp: *mut LVTime) {
     let timestamp = unsafe { timestamp.as_mut().unwrap() };
     *timestamp = LVTime::from_lv_epoch(seconds_since_epoch);
 }
 
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn timestamp_from_le_bytes(bytes: *const u8, timestamp: *mut LVTime) {
     // Safety: for this simple test we can assume we have the right size bytes.
     let mut buf = [0u8; 16];
@@ -25,6 +31,7 @@ pub extern "C" fn timestamp_from_le_bytes(bytes: *const u8, timestamp: *mut LVTi
 }
 
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn timestamp_from_be_bytes(bytes: *const u8, timestamp: *mut LVTime) {
     // Safety: for this simple test we can assume we have the right size bytes.
     let mut buf = [0u8; 16];
@@ -36,6 +43,7 @@ pub extern "C" fn timestamp_from_be_bytes(bytes: *const u8, timestamp: *mut LVTi
 }
 
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn extract_from_array(
     array_handle: LVArrayHandle<1, f64>,
     first: *mut f64,
@@ -62,6 +70,7 @@ labview_layout!(
 /// to test the different access methods available.
 #[cfg(target_pointer_width = "64")]
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn extract_test_struct_with_waveform(
     test_struct: *const TestStruct,
     one: *mut u8,
@@ -85,6 +94,7 @@ pub extern "C" fn extract_test_struct_with_waveform(
 /// to test the different access methods available.
 #[cfg(target_pointer_width = "32")]
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn extract_test_struct_with_waveform(
     test_struct: *const TestStruct,
     one: *mut u8,
@@ -94,9 +104,8 @@ pub extern "C" fn extract_test_struct_with_waveform(
     wv_last: *mut f64,
 ) {
     let _result = std::panic::catch_unwind(|| unsafe {
-        let waveform_ptr = std::ptr::addr_of!((*test_struct).waveform.data);
-        let waveform_data = std::ptr::read_unaligned(waveform_ptr);
-        let waveform_data = waveform_data.as_ref().unwrap();
+        let waveform_handle = (*test_struct).waveform.data_handle();
+        let waveform_data = waveform_handle.as_ref().unwrap();
         *one = (*test_struct).one;
         *two = (*test_struct).two;
         *three = (*test_struct).three;
@@ -105,24 +114,24 @@ pub extern "C" fn extract_test_struct_with_waveform(
     });
 }
 
-labview_layout!(
+labview_cluster!(
     pub struct ClusterHandles {
-        array1: LVArrayHandle<1, u8>,
-        array2: LVArrayHandle<2, u32>,
+        array1: LVArrayHandle<1, u8> => set_array1,
+        array2: LVArrayHandle<2, u32> => set_array2,
     }
 );
 
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn extract_cluster_handles(
     input: *const ClusterHandles,
     array1_first: *mut u8,
     array2_first: *mut u32,
 ) {
     unsafe {
-        let array1_ptr = addr_of!((*input).array1);
-        let array2_ptr = addr_of!((*input).array2);
-        let array1 = read_unaligned(array1_ptr);
-        let array2 = read_unaligned(array2_ptr);
+        let input = input.as_ref().unwrap();
+        let array1 = input.array1();
+        let array2 = input.array2();
 
         let array1_data = array1.as_ref().unwrap();
         let array2_data = array2.as_ref().unwrap();
@@ -141,6 +150,7 @@ labview_layout!(
 );
 
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 /// This is designed to see if the strange padding we've seen in waveforms
 /// is specific to variants and needs to be handled there.
 pub extern "C" fn extract_cluster_variant(
@@ -171,10 +181,10 @@ pub fn test() {
     };
 
     // Not allowed.
-    let three_ref = &value.three;
+    let _three_ref = &value.three;
 
     unsafe {
         let three_ptr: *const u32 = addr_of!(value.three);
-        let three: u32 = read_unaligned(three_ptr);
+        let _three: u32 = read_unaligned(three_ptr);
     }
 }